@@ -0,0 +1,75 @@
+//! Procedural macros for `duration-extender`.
+//!
+//! Use them through the `macros` feature of `duration-extender`, which re-exports
+//! [`duration!`](macro@duration).
+
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+// The grammar lives in the runtime crate so the macro and `parse_duration` can't drift apart.
+#[path = "../../src/grammar.rs"]
+mod grammar;
+
+/// Parses a duration string at compile time and expands to a `Duration` constant.
+///
+/// Accepts the same syntax as `duration_extender::parse_duration`, e.g.
+/// `duration!("1h 30m 500ms")`. Invalid strings are rejected at build time, and the
+/// expansion is a plain `Duration::new(..)` call, so it works in `const` items.
+#[proc_macro]
+pub fn duration(input: TokenStream) -> TokenStream {
+    let mut tokens = input.into_iter();
+    let literal = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(literal)), None) => literal,
+        (Some(token), _) => return compile_error("expected a single string literal", token.span()),
+        (None, _) => return compile_error("expected a single string literal", Span::call_site()),
+    };
+
+    let source = literal.to_string();
+    let content = match source.strip_prefix('"').and_then(|rest| rest.strip_suffix('"')) {
+        Some(content) if !content.contains('\\') => content,
+        _ => return compile_error("expected a plain string literal without escapes", literal.span()),
+    };
+
+    match grammar::parse(content) {
+        Ok((secs, nanos)) => expand(secs, nanos, literal.span()),
+        Err(error) => compile_error(error.message(), literal.span()),
+    }
+}
+
+/// Builds `::core::time::Duration::new(secs, nanos)`.
+fn expand(secs: u64, nanos: u32, span: Span) -> TokenStream {
+    let mut tokens = Vec::new();
+    for segment in ["core", "time", "Duration", "new"] {
+        tokens.push(TokenTree::Punct(Punct::new(':', Spacing::Joint)));
+        tokens.push(TokenTree::Punct(Punct::new(':', Spacing::Alone)));
+        tokens.push(TokenTree::Ident(Ident::new(segment, span)));
+    }
+    let args: TokenStream = [
+        TokenTree::Literal(Literal::u64_suffixed(secs)),
+        TokenTree::Punct(Punct::new(',', Spacing::Alone)),
+        TokenTree::Literal(Literal::u32_suffixed(nanos)),
+    ]
+    .into_iter()
+    .collect();
+    tokens.push(TokenTree::Group(Group::new(Delimiter::Parenthesis, args)));
+    tokens.into_iter().collect()
+}
+
+/// Builds `::core::compile_error!("message")` pointing at `span`.
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut message = Literal::string(message);
+    message.set_span(span);
+    let mut group = Group::new(Delimiter::Parenthesis, TokenTree::Literal(message).into());
+    group.set_span(span);
+
+    let mut tokens = Vec::new();
+    for segment in ["core", "compile_error"] {
+        tokens.push(TokenTree::Punct(Punct::new(':', Spacing::Joint)));
+        tokens.push(TokenTree::Punct(Punct::new(':', Spacing::Alone)));
+        tokens.push(TokenTree::Ident(Ident::new(segment, span)));
+    }
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+    tokens.push(TokenTree::Punct(bang));
+    tokens.push(TokenTree::Group(group));
+    tokens.into_iter().collect()
+}
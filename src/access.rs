@@ -0,0 +1,274 @@
+use core::time::Duration;
+
+const SECS_PER_MINUTE: u64 = 60;
+const SECS_PER_HOUR: u64 = 60 * SECS_PER_MINUTE;
+const SECS_PER_DAY: u64 = 24 * SECS_PER_HOUR;
+const SECS_PER_WEEK: u64 = 7 * SECS_PER_DAY;
+
+/// A `Duration` broken down into unit components, as returned by
+/// [`DurationAccessExt::decompose`].
+///
+/// Every component except `weeks` is the remainder below the next larger unit, so
+/// `hours` is always below 24 and `millis` below 1000. Weeks and days are fixed spans
+/// of 168 and 24 hours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct DurationParts {
+    /// Whole weeks.
+    pub weeks: u64,
+    /// Days below one week (0-6).
+    pub days: u64,
+    /// Hours below one day (0-23).
+    pub hours: u64,
+    /// Minutes below one hour (0-59).
+    pub minutes: u64,
+    /// Seconds below one minute (0-59).
+    pub seconds: u64,
+    /// Milliseconds below one second (0-999).
+    pub millis: u32,
+    /// Microseconds below one millisecond (0-999).
+    pub micros: u32,
+    /// Nanoseconds below one microsecond (0-999).
+    pub nanos: u32,
+}
+
+impl DurationParts {
+    /// Adds the components back up into a `Duration`.
+    ///
+    /// Returns `None` if the total exceeds `Duration`'s capacity, which can only happen
+    /// for hand-built parts.
+    pub fn to_duration(&self) -> Option<Duration> {
+        let secs = self
+            .weeks
+            .checked_mul(SECS_PER_WEEK)?
+            .checked_add(self.days.checked_mul(SECS_PER_DAY)?)?
+            .checked_add(self.hours.checked_mul(SECS_PER_HOUR)?)?
+            .checked_add(self.minutes.checked_mul(SECS_PER_MINUTE)?)?
+            .checked_add(self.seconds)?;
+        let subsec = Duration::from_millis(u64::from(self.millis))
+            + Duration::from_micros(u64::from(self.micros))
+            + Duration::from_nanos(u64::from(self.nanos));
+        Duration::from_secs(secs).checked_add(subsec)
+    }
+}
+
+/// An extension trait that reads a `Duration` back in the units this crate constructs.
+///
+/// This is the inverse of the fluent constructors: [`decompose`](Self::decompose) splits a
+/// duration into its components for display or logging, and the `whole_*` accessors
+/// complement `Duration::as_secs()` for larger units. Weeks and days are fixed spans
+/// of 168 and 24 hours.
+///
+/// The loss of each accessor is explicit in its name: `whole_*` truncates,
+/// `*_and_remainder` returns the truncated part alongside, and `as_*_f64` is fractional
+/// with the usual `f64` rounding, like `Duration::as_secs_f64()`.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationAccessExt, DurationExt};
+///
+/// let elapsed = 26.hours() + 3.minutes() + 250.milliseconds();
+/// let parts = elapsed.decompose();
+/// assert_eq!((parts.days, parts.hours, parts.minutes, parts.millis), (1, 2, 3, 250));
+///
+/// assert_eq!(elapsed.whole_hours(), 26);
+/// assert_eq!(elapsed.subsec_minutes(), 3);
+/// assert_eq!(elapsed.whole_days(), 1);
+/// assert_eq!(elapsed.days_and_remainder(), (1, 2.hours() + 3.minutes() + 250.milliseconds()));
+/// assert_eq!(90.minutes().as_hours_f64(), 1.5);
+/// ```
+pub trait DurationAccessExt {
+    /// Splits this duration into weeks, days, hours, minutes, seconds and sub-second parts.
+    fn decompose(&self) -> DurationParts;
+    /// Returns the number of whole weeks in this duration.
+    fn whole_weeks(&self) -> u64;
+    /// Returns the number of whole days in this duration.
+    fn whole_days(&self) -> u64;
+    /// Returns the number of whole hours in this duration.
+    fn whole_hours(&self) -> u64;
+    /// Returns the number of whole minutes in this duration.
+    fn whole_minutes(&self) -> u64;
+    /// Returns the minutes within the current hour (0-59), like `Duration::subsec_millis`
+    /// does for milliseconds within the second.
+    ///
+    /// This is the `minutes` component of [`decompose`](Self::decompose).
+    fn subsec_minutes(&self) -> u64;
+    /// Returns the whole weeks in this duration and the time left over.
+    fn weeks_and_remainder(&self) -> (u64, Duration);
+    /// Returns the whole days in this duration and the time left over.
+    fn days_and_remainder(&self) -> (u64, Duration);
+    /// Returns the whole hours in this duration and the time left over.
+    fn hours_and_remainder(&self) -> (u64, Duration);
+    /// Returns the whole minutes in this duration and the time left over.
+    fn minutes_and_remainder(&self) -> (u64, Duration);
+    /// Returns this duration as fractional weeks.
+    fn as_weeks_f64(&self) -> f64;
+    /// Returns this duration as fractional days.
+    fn as_days_f64(&self) -> f64;
+    /// Returns this duration as fractional hours.
+    fn as_hours_f64(&self) -> f64;
+    /// Returns this duration as fractional minutes.
+    fn as_minutes_f64(&self) -> f64;
+}
+
+impl DurationAccessExt for Duration {
+    fn decompose(&self) -> DurationParts {
+        let secs = self.as_secs();
+        let nanos = self.subsec_nanos();
+        DurationParts {
+            weeks: secs / SECS_PER_WEEK,
+            days: secs % SECS_PER_WEEK / SECS_PER_DAY,
+            hours: secs % SECS_PER_DAY / SECS_PER_HOUR,
+            minutes: secs % SECS_PER_HOUR / SECS_PER_MINUTE,
+            seconds: secs % SECS_PER_MINUTE,
+            millis: nanos / 1_000_000,
+            micros: nanos / 1_000 % 1_000,
+            nanos: nanos % 1_000,
+        }
+    }
+
+    fn whole_weeks(&self) -> u64 {
+        self.as_secs() / SECS_PER_WEEK
+    }
+
+    fn whole_days(&self) -> u64 {
+        self.as_secs() / SECS_PER_DAY
+    }
+
+    fn whole_hours(&self) -> u64 {
+        self.as_secs() / SECS_PER_HOUR
+    }
+
+    fn whole_minutes(&self) -> u64 {
+        self.as_secs() / SECS_PER_MINUTE
+    }
+
+    fn subsec_minutes(&self) -> u64 {
+        self.as_secs() % SECS_PER_HOUR / SECS_PER_MINUTE
+    }
+
+    fn weeks_and_remainder(&self) -> (u64, Duration) {
+        split(*self, SECS_PER_WEEK)
+    }
+
+    fn days_and_remainder(&self) -> (u64, Duration) {
+        split(*self, SECS_PER_DAY)
+    }
+
+    fn hours_and_remainder(&self) -> (u64, Duration) {
+        split(*self, SECS_PER_HOUR)
+    }
+
+    fn minutes_and_remainder(&self) -> (u64, Duration) {
+        split(*self, SECS_PER_MINUTE)
+    }
+
+    fn as_weeks_f64(&self) -> f64 {
+        self.as_secs_f64() / SECS_PER_WEEK as f64
+    }
+
+    fn as_days_f64(&self) -> f64 {
+        self.as_secs_f64() / SECS_PER_DAY as f64
+    }
+
+    fn as_hours_f64(&self) -> f64 {
+        self.as_secs_f64() / SECS_PER_HOUR as f64
+    }
+
+    fn as_minutes_f64(&self) -> f64 {
+        self.as_secs_f64() / SECS_PER_MINUTE as f64
+    }
+}
+
+/// Splits `duration` into whole units of `secs_per_unit` seconds and the remainder.
+fn split(duration: Duration, secs_per_unit: u64) -> (u64, Duration) {
+    let secs = duration.as_secs();
+    let remainder = Duration::new(secs % secs_per_unit, duration.subsec_nanos());
+    (secs / secs_per_unit, remainder)
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_decompose_every_component() {
+        let value = (8 * 24 + 3).hours() + 4.minutes() + 5.seconds()
+            + 6.milliseconds() + 7.microseconds() + 8.nanoseconds();
+        let expected = DurationParts {
+            weeks: 1,
+            days: 1,
+            hours: 3,
+            minutes: 4,
+            seconds: 5,
+            millis: 6,
+            micros: 7,
+            nanos: 8,
+        };
+        assert_eq!(value.decompose(), expected);
+    }
+
+    #[test]
+    fn test_decompose_zero() {
+        assert_eq!(Duration::ZERO.decompose(), DurationParts::default());
+    }
+
+    #[test]
+    fn test_decompose_round_trip() {
+        for value in [Duration::ZERO, 90.minutes() + 1.nanoseconds(), Duration::MAX] {
+            assert_eq!(value.decompose().to_duration(), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_to_duration_overflow() {
+        let parts = DurationParts { weeks: u64::MAX, ..DurationParts::default() };
+        assert_eq!(parts.to_duration(), None);
+    }
+
+    #[test]
+    fn test_whole_units() {
+        let value = (15 * 24 + 5).hours() + 59.minutes();
+        assert_eq!(value.whole_weeks(), 2);
+        assert_eq!(value.whole_days(), 15);
+        assert_eq!(value.whole_hours(), 15 * 24 + 5);
+        assert_eq!(value.whole_minutes(), (15 * 24 + 5) * 60 + 59);
+    }
+
+    #[test]
+    fn test_subsec_minutes() {
+        assert_eq!(Duration::ZERO.subsec_minutes(), 0);
+        assert_eq!((2.hours() + 59.minutes() + 59.seconds()).subsec_minutes(), 59);
+        assert_eq!(3.hours().subsec_minutes(), 0);
+        assert_eq!(Duration::MAX.subsec_minutes(), Duration::MAX.decompose().minutes);
+    }
+
+    #[test]
+    fn test_and_remainder() {
+        let value = (15 * 24 + 5).hours() + 59.minutes() + 1.nanoseconds();
+        assert_eq!(value.weeks_and_remainder(), (2, (24 + 5).hours() + 59.minutes() + 1.nanoseconds()));
+        assert_eq!(value.days_and_remainder(), (15, 5.hours() + 59.minutes() + 1.nanoseconds()));
+        assert_eq!(value.hours_and_remainder(), (15 * 24 + 5, 59.minutes() + 1.nanoseconds()));
+        assert_eq!(value.minutes_and_remainder(), ((15 * 24 + 5) * 60 + 59, 1.nanoseconds()));
+        assert_eq!(Duration::ZERO.days_and_remainder(), (0, Duration::ZERO));
+    }
+
+    #[test]
+    fn test_and_remainder_round_trip() {
+        for value in [Duration::ZERO, 90.minutes() + 1.nanoseconds(), Duration::MAX] {
+            let (days, remainder) = value.days_and_remainder();
+            assert_eq!(Duration::from_secs(days * SECS_PER_DAY) + remainder, value);
+        }
+    }
+
+    #[test]
+    fn test_fractional_units() {
+        assert_eq!(90.seconds().as_minutes_f64(), 1.5);
+        assert_eq!(90.minutes().as_hours_f64(), 1.5);
+        assert_eq!(36.hours().as_days_f64(), 1.5);
+        assert_eq!((21 * 12).hours().as_weeks_f64(), 1.5);
+        assert_eq!(Duration::ZERO.as_weeks_f64(), 0.0);
+    }
+}
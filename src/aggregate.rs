@@ -0,0 +1,169 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::borrow::Borrow;
+use core::time::Duration;
+
+use crate::value::duration_from_nanos;
+
+/// An extension trait that aggregates iterators of durations, e.g. per-request latencies.
+///
+/// `iter().sum::<Duration>()` panics on overflow and std has no average; these helpers
+/// return `None` for empty input or overflow instead. Works with iterators of both
+/// `Duration` and `&Duration`.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, DurationIteratorExt};
+///
+/// let latencies = [12.milliseconds(), 30.milliseconds(), 18.milliseconds()];
+/// assert_eq!(latencies.iter().sum_checked(), Some(60.milliseconds()));
+/// assert_eq!(latencies.iter().average(), Some(20.milliseconds()));
+/// assert_eq!(latencies.iter().min_max(), Some((12.milliseconds(), 30.milliseconds())));
+/// ```
+pub trait DurationIteratorExt: Iterator + Sized
+where
+    Self::Item: Borrow<Duration>,
+{
+    /// Returns the sum of all durations, or `None` on overflow.
+    fn sum_checked(mut self) -> Option<Duration> {
+        self.try_fold(Duration::ZERO, |sum, duration| sum.checked_add(*duration.borrow()))
+    }
+
+    /// Returns the sum of all durations, clamped to `Duration::MAX`.
+    fn sum_saturating(self) -> Duration {
+        self.fold(Duration::ZERO, |sum, duration| sum.saturating_add(*duration.borrow()))
+    }
+
+    /// Returns the mean duration, truncated to whole nanoseconds, or `None` if the
+    /// iterator is empty.
+    ///
+    /// The total is accumulated in `u128` nanoseconds, so unlike [`sum_checked`](Self::sum_checked)
+    /// it does not overflow for realistic inputs.
+    fn average(self) -> Option<Duration> {
+        let (total, count) = self.fold((0u128, 0u128), |(total, count), duration| {
+            (total.saturating_add(duration.borrow().as_nanos()), count + 1)
+        });
+        if count == 0 {
+            return None;
+        }
+        // The mean never exceeds the largest element, so it always fits.
+        duration_from_nanos(total / count)
+    }
+
+    /// Returns the shortest and the longest duration, or `None` if the iterator is empty.
+    fn min_max(self) -> Option<(Duration, Duration)> {
+        self.fold(None, |bounds, duration| {
+            let duration = *duration.borrow();
+            match bounds {
+                None => Some((duration, duration)),
+                Some((min, max)) => Some((min.min(duration), max.max(duration))),
+            }
+        })
+    }
+
+    /// Returns the `p`-th percentile (nearest-rank method), or `None` if the iterator is empty.
+    ///
+    /// `p` is a percentage from `0.0` to `100.0`: `percentile(50.0)` is the median and
+    /// `percentile(99.0)` the p99 latency. The result is always one of the input durations.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is NaN or outside `0.0..=100.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use duration_extender::{DurationExt, DurationIteratorExt};
+    ///
+    /// let latencies = [12.milliseconds(), 30.milliseconds(), 18.milliseconds()];
+    /// assert_eq!(latencies.iter().percentile(50.0), Some(18.milliseconds()));
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[track_caller]
+    fn percentile(self, p: f64) -> Option<Duration> {
+        assert!((0.0..=100.0).contains(&p), "percentile must be between 0.0 and 100.0: got {}", p);
+        let mut sorted: Vec<Duration> = self.map(|duration| *duration.borrow()).collect();
+        if sorted.is_empty() {
+            return None;
+        }
+        sorted.sort_unstable();
+        // Nearest rank: the 1-based rank is `ceil(p / 100 * n)`, at least 1. `f64::ceil`
+        // needs `std`, so round up by hand.
+        let position = p / 100.0 * sorted.len() as f64;
+        let mut rank = position as usize;
+        if (rank as f64) < position {
+            rank += 1;
+        }
+        Some(sorted[rank.max(1) - 1])
+    }
+}
+
+impl<I> DurationIteratorExt for I
+where
+    I: Iterator,
+    I::Item: Borrow<Duration>,
+{
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_sums() {
+        let values = [1.seconds(), 2.seconds()];
+        assert_eq!(values.iter().sum_checked(), Some(3.seconds()));
+        assert_eq!(values.into_iter().sum_saturating(), 3.seconds());
+
+        let huge = [Duration::MAX, 1.nanoseconds()];
+        assert_eq!(huge.iter().sum_checked(), None);
+        assert_eq!(huge.iter().sum_saturating(), Duration::MAX);
+        assert_eq!(core::iter::empty::<Duration>().sum_checked(), Some(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_average() {
+        assert_eq!([1.seconds(), 2.seconds()].iter().average(), Some(1500.milliseconds()));
+        assert_eq!([Duration::MAX, Duration::MAX].iter().average(), Some(Duration::MAX));
+        assert_eq!([1.nanoseconds(), 2.nanoseconds()].iter().average(), Some(1.nanoseconds()));
+        assert_eq!(core::iter::empty::<&Duration>().average(), None);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let values = [5.seconds(), 1.seconds(), 9.seconds()];
+        assert_eq!(values.iter().min_max(), Some((1.seconds(), 9.seconds())));
+        assert_eq!([3.seconds()].iter().min_max(), Some((3.seconds(), 3.seconds())));
+        assert_eq!(core::iter::empty::<Duration>().min_max(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_percentile_nearest_rank() {
+        let values: Vec<Duration> = (1..=10).map(|ms| ms.milliseconds()).collect();
+        assert_eq!(values.iter().percentile(0.0), Some(1.milliseconds()));
+        assert_eq!(values.iter().percentile(50.0), Some(5.milliseconds()));
+        assert_eq!(values.iter().percentile(55.0), Some(6.milliseconds()));
+        assert_eq!(values.iter().percentile(90.0), Some(9.milliseconds()));
+        assert_eq!(values.iter().percentile(99.0), Some(10.milliseconds()));
+        assert_eq!(values.iter().percentile(100.0), Some(10.milliseconds()));
+        assert_eq!(core::iter::empty::<Duration>().percentile(50.0), None);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_percentile_unsorted_input() {
+        let values = [30.milliseconds(), 10.milliseconds(), 20.milliseconds()];
+        assert_eq!(values.iter().percentile(50.0), Some(20.milliseconds()));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic(expected = "percentile must be between 0.0 and 100.0")]
+    fn test_percentile_out_of_range_panics() {
+        let _ = [1.seconds()].iter().percentile(101.0);
+    }
+}
@@ -0,0 +1,230 @@
+use core::time::Duration;
+
+use crate::value::{self, IntoDurationValue, NANOS_PER_HOUR, NANOS_PER_SEC};
+
+const SECS_PER_DAY: u64 = 86_400;
+
+/// The fixed length used to turn calendar months and years into a `Duration`.
+///
+/// Real months and years vary in length, so a `Duration` can only ever approximate
+/// them. This enum makes the chosen approximation visible at every call site.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CalendarApprox {
+    /// 30-day months and 365-day years.
+    ///
+    /// Note that twelve of these months (360 days) are shorter than one year.
+    Fixed,
+    /// Average Gregorian lengths: 30.436875-day months (2,629,746 seconds) and
+    /// 365.2425-day years (31,556,952 seconds).
+    ///
+    /// Twelve of these months are exactly one year.
+    Average,
+}
+
+impl CalendarApprox {
+    /// Returns the length of one month under this approximation.
+    pub const fn month(self) -> Duration {
+        Duration::from_secs(self.month_secs())
+    }
+
+    /// Returns the length of one year under this approximation.
+    pub const fn year(self) -> Duration {
+        Duration::from_secs(self.year_secs())
+    }
+
+    const fn month_secs(self) -> u64 {
+        match self {
+            CalendarApprox::Fixed => 30 * SECS_PER_DAY,
+            CalendarApprox::Average => 2_629_746,
+        }
+    }
+
+    const fn year_secs(self) -> u64 {
+        match self {
+            CalendarApprox::Fixed => 365 * SECS_PER_DAY,
+            CalendarApprox::Average => 31_556_952,
+        }
+    }
+}
+
+/// An extension trait that adds approximate `.months()` and `.years()` constructors.
+///
+/// These are meant for coarse retention windows ("delete after 6 months"), never for
+/// calendar arithmetic: the result is a fixed span, so adding it to a date will drift
+/// from the calendar. Each call takes a [`CalendarApprox`] so the approximation is
+/// spelled out where it is used.
+///
+/// # Panics
+///
+/// Panics on the same inputs as [`DurationExt`](crate::DurationExt): negative values,
+/// NaN or infinite floats, and results exceeding `u64::MAX` seconds. Use the
+/// `checked_*` variants to get `None` instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{CalendarApprox, CalendarApproxExt, DurationExt};
+///
+/// let retention = 6.months(CalendarApprox::Fixed);
+/// assert_eq!(retention, (6 * 30 * 24).hours());
+///
+/// assert_eq!(12.months(CalendarApprox::Average), 1.years(CalendarApprox::Average));
+/// ```
+pub trait CalendarApproxExt {
+    /// Creates a `Duration` representing this many months of the given approximate length.
+    fn months(self, approx: CalendarApprox) -> Duration;
+    /// Creates a `Duration` representing this many years of the given approximate length.
+    fn years(self, approx: CalendarApprox) -> Duration;
+    /// Like [`months`](Self::months), but returns `None` instead of panicking.
+    fn checked_months(self, approx: CalendarApprox) -> Option<Duration>;
+    /// Like [`years`](Self::years), but returns `None` instead of panicking.
+    fn checked_years(self, approx: CalendarApprox) -> Option<Duration>;
+}
+
+impl<T: IntoDurationValue> CalendarApproxExt for T {
+    #[track_caller]
+    fn months(self, approx: CalendarApprox) -> Duration {
+        value::to_duration(self, approx.month_secs() * NANOS_PER_SEC, "months")
+    }
+
+    #[track_caller]
+    fn years(self, approx: CalendarApprox) -> Duration {
+        value::to_duration(self, approx.year_secs() * NANOS_PER_SEC, "years")
+    }
+
+    fn checked_months(self, approx: CalendarApprox) -> Option<Duration> {
+        self.checked_units(approx.month_secs() * NANOS_PER_SEC).ok()
+    }
+
+    fn checked_years(self, approx: CalendarApprox) -> Option<Duration> {
+        self.checked_units(approx.year_secs() * NANOS_PER_SEC).ok()
+    }
+}
+
+/// An extension trait for days and weeks as explicitly fixed spans: `2.fixed_days()`.
+///
+/// This crate has no `.days()` or `.weeks()`, and `.months()` needs a
+/// [`CalendarApprox`], so code cannot assume a calendar length by accident:
+///
+/// ```compile_fail
+/// use duration_extender::DurationExt;
+///
+/// let retention = 2.days(); // error: no method named `days`
+/// ```
+///
+/// ```compile_fail
+/// use duration_extender::{CalendarApproxExt, DurationExt};
+///
+/// let retention = 6.months(); // error: missing the `CalendarApprox` argument
+/// ```
+///
+/// Where a fixed 24-hour day or 168-hour week is really meant, such as a monotonic
+/// cache TTL, these methods spell out that assumption at the call site. Across a DST
+/// change a wall-clock day is 23 or 25 hours; these never are.
+///
+/// # Panics
+///
+/// Panics on the same inputs as [`DurationExt`](crate::DurationExt). Use the
+/// `checked_*` variants to get `None` instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, FixedLengthExt};
+///
+/// assert_eq!(2.fixed_days(), 48.hours());
+/// assert_eq!(1.fixed_weeks(), (7 * 24).hours());
+/// assert_eq!(u64::MAX.checked_fixed_days(), None);
+/// ```
+pub trait FixedLengthExt {
+    /// Creates a `Duration` representing this many fixed 24-hour days.
+    fn fixed_days(self) -> Duration;
+    /// Creates a `Duration` representing this many fixed 168-hour weeks.
+    fn fixed_weeks(self) -> Duration;
+    /// Like [`fixed_days`](Self::fixed_days), but returns `None` instead of panicking.
+    fn checked_fixed_days(self) -> Option<Duration>;
+    /// Like [`fixed_weeks`](Self::fixed_weeks), but returns `None` instead of panicking.
+    fn checked_fixed_weeks(self) -> Option<Duration>;
+}
+
+impl<T: IntoDurationValue> FixedLengthExt for T {
+    #[track_caller]
+    fn fixed_days(self) -> Duration {
+        value::to_duration(self, 24 * NANOS_PER_HOUR, "fixed days")
+    }
+
+    #[track_caller]
+    fn fixed_weeks(self) -> Duration {
+        value::to_duration(self, 7 * 24 * NANOS_PER_HOUR, "fixed weeks")
+    }
+
+    fn checked_fixed_days(self) -> Option<Duration> {
+        self.checked_units(24 * NANOS_PER_HOUR).ok()
+    }
+
+    fn checked_fixed_weeks(self) -> Option<Duration> {
+        self.checked_units(7 * 24 * NANOS_PER_HOUR).ok()
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_fixed_lengths() {
+        assert_eq!(1.months(CalendarApprox::Fixed), (30 * 24).hours());
+        assert_eq!(1.years(CalendarApprox::Fixed), (365 * 24).hours());
+        assert!(12.months(CalendarApprox::Fixed) < 1.years(CalendarApprox::Fixed));
+    }
+
+    #[test]
+    fn test_average_lengths() {
+        assert_eq!(CalendarApprox::Average.month(), Duration::from_secs(2_629_746));
+        assert_eq!(CalendarApprox::Average.year(), Duration::from_secs(31_556_952));
+        assert_eq!(12.months(CalendarApprox::Average), 1.years(CalendarApprox::Average));
+    }
+
+    #[test]
+    fn test_fractional_months() {
+        assert_eq!(0.5.months(CalendarApprox::Fixed), (15 * 24).hours());
+    }
+
+    #[test]
+    fn test_checked_variants() {
+        assert_eq!(6u8.checked_months(CalendarApprox::Fixed), Some(6.months(CalendarApprox::Fixed)));
+        assert_eq!((-1i32).checked_years(CalendarApprox::Average), None);
+        assert_eq!(u64::MAX.checked_years(CalendarApprox::Fixed), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "duration value 18446744073709551615 years overflows u64 seconds capacity")]
+    fn test_years_panics_on_overflow() {
+        let _ = u64::MAX.years(CalendarApprox::Average);
+    }
+
+    #[test]
+    fn test_fixed_days_and_weeks() {
+        assert_eq!(2.fixed_days(), 48.hours());
+        assert_eq!(1.5.fixed_days(), 36.hours());
+        assert_eq!(3u8.fixed_weeks(), (3 * 7 * 24).hours());
+        assert_eq!(7.fixed_days(), 1.fixed_weeks());
+        assert_eq!((-1).checked_fixed_weeks(), None);
+        assert_eq!(u64::MAX.checked_fixed_weeks(), None);
+        assert_eq!(1.checked_fixed_days(), Some(crate::consts::DAY));
+    }
+
+    #[test]
+    #[should_panic(expected = "duration cannot be negative: got -2 fixed days")]
+    fn test_fixed_days_panics_on_negative() {
+        let _ = (-2).fixed_days();
+    }
+
+    #[test]
+    #[should_panic(expected = "duration cannot be negative: got -6 months")]
+    fn test_months_panics_on_negative() {
+        let _ = (-6).months(CalendarApprox::Fixed);
+    }
+}
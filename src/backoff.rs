@@ -0,0 +1,215 @@
+use core::time::Duration;
+
+/// An iterator of retry delays that grow by a constant factor.
+///
+/// Built fluently from this crate's durations:
+/// `Backoff::exponential(100.milliseconds()).factor(2.0).max_delay(30.seconds()).max_retries(10)`.
+/// Growth saturates instead of overflowing, so an unbounded backoff eventually
+/// yields `Duration::MAX` (or `max_delay`) forever.
+///
+/// Whole-number factors grow the delay exactly; fractional factors go through `f64`
+/// seconds and may be off by a few nanoseconds.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{Backoff, DurationExt};
+///
+/// let delays: Vec<_> = Backoff::exponential(100.milliseconds())
+///     .max_delay(1.seconds())
+///     .max_retries(5)
+///     .collect();
+///
+/// assert_eq!(
+///     delays,
+///     [100.milliseconds(), 200.milliseconds(), 400.milliseconds(), 800.milliseconds(), 1.seconds()]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Backoff {
+    initial: Duration,
+    current: Duration,
+    factor: f64,
+    max_delay: Duration,
+    max_retries: Option<u32>,
+    attempts: u32,
+    #[cfg(feature = "rand")]
+    full_jitter: bool,
+}
+
+impl Backoff {
+    /// Creates a backoff that starts at `initial` and doubles after every attempt.
+    pub fn exponential(initial: Duration) -> Self {
+        Backoff {
+            initial,
+            current: initial,
+            factor: 2.0,
+            max_delay: Duration::MAX,
+            max_retries: None,
+            attempts: 0,
+            #[cfg(feature = "rand")]
+            full_jitter: false,
+        }
+    }
+
+    /// Creates a backoff that always yields `delay`.
+    pub fn constant(delay: Duration) -> Self {
+        Backoff::exponential(delay).factor(1.0)
+    }
+
+    /// Sets the factor the delay is multiplied by after every attempt.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is not finite or is less than `1.0`.
+    #[track_caller]
+    pub fn factor(mut self, factor: f64) -> Self {
+        assert!(
+            factor.is_finite() && factor >= 1.0,
+            "backoff factor must be finite and at least 1.0: got {}",
+            factor
+        );
+        self.factor = factor;
+        self
+    }
+
+    /// Caps every yielded delay at `max_delay`.
+    pub fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Stops the iterator after `max_retries` delays.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = Some(max_retries);
+        self
+    }
+
+    /// Randomizes every delay between zero and its computed value ("full jitter").
+    ///
+    /// Spreads out retries from many clients that started failing at the same time.
+    /// The underlying exponential sequence, and therefore `max_delay`, is unaffected.
+    #[cfg(feature = "rand")]
+    pub fn with_full_jitter(mut self) -> Self {
+        self.full_jitter = true;
+        self
+    }
+
+    /// Returns how many delays have been yielded so far.
+    pub fn attempts(&self) -> u32 {
+        self.attempts
+    }
+
+    /// Restarts the sequence from the initial delay, keeping the configuration.
+    pub fn reset(&mut self) {
+        self.current = self.initial;
+        self.attempts = 0;
+    }
+}
+
+impl Iterator for Backoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        if self.max_retries.is_some_and(|max| self.attempts >= max) {
+            return None;
+        }
+        self.attempts = self.attempts.saturating_add(1);
+
+        let delay = self.current.min(self.max_delay);
+        // Clamping the stored delay keeps it from growing past the cap, so the float
+        // path below never has to represent values far beyond `max_delay`.
+        self.current = grow(delay, self.factor).min(self.max_delay);
+
+        #[cfg(feature = "rand")]
+        if self.full_jitter {
+            return Some(crate::JitterExt::jitter_between(delay, 0.0, 1.0));
+        }
+        Some(delay)
+    }
+}
+
+/// Multiplies `delay` by `factor`, saturating at `Duration::MAX`.
+fn grow(delay: Duration, factor: f64) -> Duration {
+    // `f64::fract` needs `std`, so detect whole factors by round-tripping through `u32`.
+    let whole = factor as u32;
+    if f64::from(whole) == factor {
+        delay.checked_mul(whole).unwrap_or(Duration::MAX)
+    } else {
+        Duration::try_from_secs_f64(delay.as_secs_f64() * factor).unwrap_or(Duration::MAX)
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_exponential_doubles() {
+        let mut backoff = Backoff::exponential(100.milliseconds());
+        assert_eq!(backoff.next(), Some(100.milliseconds()));
+        assert_eq!(backoff.next(), Some(200.milliseconds()));
+        assert_eq!(backoff.next(), Some(400.milliseconds()));
+        assert_eq!(backoff.attempts(), 3);
+    }
+
+    #[test]
+    fn test_custom_factor() {
+        let delays: Vec<_> = Backoff::exponential(1.seconds()).factor(3.0).max_retries(3).collect();
+        assert_eq!(delays, [1.seconds(), 3.seconds(), 9.seconds()]);
+
+        let delays: Vec<_> = Backoff::exponential(1.seconds()).factor(1.5).max_retries(3).collect();
+        assert_eq!(delays, [1.seconds(), 1500.milliseconds(), 2250.milliseconds()]);
+    }
+
+    #[test]
+    fn test_max_delay_caps() {
+        let delays: Vec<_> = Backoff::exponential(1.seconds()).max_delay(3.seconds()).max_retries(4).collect();
+        assert_eq!(delays, [1.seconds(), 2.seconds(), 3.seconds(), 3.seconds()]);
+    }
+
+    #[test]
+    fn test_max_retries_stops() {
+        assert_eq!(Backoff::constant(1.seconds()).max_retries(0).next(), None);
+        assert_eq!(Backoff::constant(1.seconds()).max_retries(7).count(), 7);
+    }
+
+    #[test]
+    fn test_growth_saturates() {
+        let mut backoff = Backoff::exponential(u64::MAX.seconds() / 2);
+        backoff.next();
+        backoff.next();
+        assert_eq!(backoff.next(), Some(Duration::MAX));
+        assert_eq!(backoff.next(), Some(Duration::MAX));
+
+        let mut fractional = Backoff::exponential(Duration::MAX).factor(1.5);
+        fractional.next();
+        assert_eq!(fractional.next(), Some(Duration::MAX));
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut backoff = Backoff::exponential(1.seconds()).max_retries(2);
+        assert_eq!(backoff.by_ref().count(), 2);
+        backoff.reset();
+        assert_eq!(backoff.next(), Some(1.seconds()));
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_full_jitter_stays_below_delay() {
+        let plain = Backoff::exponential(100.milliseconds()).max_retries(8);
+        let jittered = plain.clone().with_full_jitter();
+        for (jittered, plain) in jittered.zip(plain) {
+            assert!(jittered <= plain, "{:?} > {:?}", jittered, plain);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "backoff factor must be finite and at least 1.0")]
+    fn test_factor_below_one_panics() {
+        let _ = Backoff::exponential(1.seconds()).factor(0.5);
+    }
+}
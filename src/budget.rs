@@ -0,0 +1,154 @@
+use std::time::{Duration, Instant};
+
+/// A total time budget shared across sequential steps, e.g. the RPC calls of one request.
+///
+/// Created with [`BudgetExt::budget`]: `30.seconds().budget()`. The budget starts
+/// draining immediately, and every reading saturates at zero instead of underflowing,
+/// so there is no `Instant` arithmetic left to get wrong in call chains. A budget too
+/// large to represent as an `Instant` never expires.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{BudgetExt, DurationExt};
+///
+/// let budget = 30.seconds().budget();
+/// assert!(!budget.expired());
+/// assert!(budget.remaining() <= 30.seconds());
+///
+/// // Give each of three sequential calls a fair share of what is left.
+/// let per_call = budget.split(3);
+/// assert!(per_call <= 10.seconds());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Budget {
+    total: Duration,
+    started: Instant,
+    deadline: Option<Instant>,
+}
+
+impl Budget {
+    /// Creates a budget of `total` that starts draining now.
+    pub fn new(total: Duration) -> Self {
+        Budget::starting_at(total, Instant::now())
+    }
+
+    fn starting_at(total: Duration, started: Instant) -> Self {
+        Budget { total, started, deadline: started.checked_add(total) }
+    }
+
+    /// Returns the size of the budget when it was created.
+    pub fn total(&self) -> Duration {
+        self.total
+    }
+
+    /// Returns the instant the budget runs out, or `None` if it never does.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.deadline
+    }
+
+    /// Returns the time spent since the budget was created.
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Returns the time left, or `Duration::ZERO` once the budget is used up.
+    pub fn remaining(&self) -> Duration {
+        self.remaining_at(Instant::now())
+    }
+
+    /// Returns `true` once no time is left.
+    pub fn expired(&self) -> bool {
+        self.remaining().is_zero()
+    }
+
+    /// Returns an equal share of the remaining time for each of `parts` steps.
+    ///
+    /// Call it again before each step with the number of steps still to go, so time
+    /// left over by fast steps is passed on to slower ones.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parts` is zero.
+    #[track_caller]
+    pub fn split(&self, parts: u32) -> Duration {
+        assert!(parts > 0, "cannot split a budget into zero parts");
+        self.remaining() / parts
+    }
+
+    /// Returns a budget of at most `limit` that never outlasts this one, e.g. a per-call
+    /// timeout within a request budget.
+    pub fn child(&self, limit: Duration) -> Budget {
+        Budget::new(limit.min(self.remaining()))
+    }
+
+    fn remaining_at(&self, now: Instant) -> Duration {
+        match self.deadline {
+            Some(deadline) => deadline.saturating_duration_since(now),
+            None => Duration::MAX,
+        }
+    }
+}
+
+/// An extension trait that turns a `Duration` into a [`Budget`].
+pub trait BudgetExt {
+    /// Starts a [`Budget`] of this duration.
+    fn budget(self) -> Budget;
+}
+
+impl BudgetExt for Duration {
+    fn budget(self) -> Budget {
+        Budget::new(self)
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_remaining_drains_and_saturates() {
+        let origin = Instant::now();
+        let budget = Budget::starting_at(30.seconds(), origin);
+        assert_eq!(budget.remaining_at(origin), 30.seconds());
+        assert_eq!(budget.remaining_at(origin + 12.seconds()), 18.seconds());
+        assert_eq!(budget.remaining_at(origin + 1.hours()), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_expired() {
+        assert!(Duration::ZERO.budget().expired());
+        assert!(!1.hours().budget().expired());
+    }
+
+    #[test]
+    fn test_split() {
+        let budget = 30.seconds().budget();
+        assert!(budget.split(3) <= 10.seconds());
+        assert!(budget.split(3) > 9.seconds());
+        assert_eq!(Duration::ZERO.budget().split(4), Duration::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot split a budget into zero parts")]
+    fn test_split_zero_panics() {
+        let _ = 1.seconds().budget().split(0);
+    }
+
+    #[test]
+    fn test_child_is_capped_by_parent() {
+        let parent = 10.seconds().budget();
+        assert!(parent.child(1.hours()).total() <= 10.seconds());
+        assert_eq!(parent.child(2.seconds()).total(), 2.seconds());
+    }
+
+    #[test]
+    fn test_unrepresentable_budget_never_expires() {
+        let budget = Duration::MAX.budget();
+        assert_eq!(budget.deadline(), None);
+        assert_eq!(budget.remaining(), Duration::MAX);
+        assert!(!budget.expired());
+    }
+}
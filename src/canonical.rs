@@ -0,0 +1,218 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use core::fmt::Write;
+use core::time::Duration;
+
+use crate::parse::ParseDurationError;
+
+/// The units of the canonical form, largest first, with the exclusive upper limit of
+/// each component (weeks are unbounded) and its length in nanoseconds.
+const UNITS: [(&str, Option<u64>, u128); 8] = [
+    ("w", None, 604_800_000_000_000),
+    ("d", Some(7), 86_400_000_000_000),
+    ("h", Some(24), 3_600_000_000_000),
+    ("m", Some(60), 60_000_000_000),
+    ("s", Some(60), 1_000_000_000),
+    ("ms", Some(1000), 1_000_000),
+    ("us", Some(1000), 1_000),
+    ("ns", Some(1000), 1),
+];
+
+/// An extension trait that formats a `Duration` in the canonical form of this crate, e.g. `"1w2d3h4m5s6ms"`.
+///
+/// The canonical form is meant for storage, such as a text column in a database, and
+/// is stable across releases:
+///
+/// - components are written largest first from `w`, `d`, `h`, `m`, `s`, `ms`, `us`
+///   and `ns`, without separators;
+/// - zero components are left out, and every other component is below the next
+///   larger unit (so `d` is at most 6 and `h` at most 23), with no leading zeros;
+/// - the zero duration is `"0s"`.
+///
+/// Weeks and days are fixed spans of 168 and 24 hours. Every `Duration` has exactly
+/// one canonical string, and [`from_canonical_str`] accepts exactly those strings, so
+/// `from_canonical_str(&d.to_canonical_string()) == Ok(d)` for every `d`.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{from_canonical_str, CanonicalExt, DurationExt};
+///
+/// let stored = (24 * 9 + 3).hours() + 4.minutes() + 5.seconds() + 6.milliseconds();
+/// assert_eq!(stored.to_canonical_string(), "1w2d3h4m5s6ms");
+/// assert_eq!(from_canonical_str("1w2d3h4m5s6ms"), Ok(stored));
+/// ```
+#[cfg(feature = "alloc")]
+pub trait CanonicalExt {
+    /// Formats this duration in the canonical form.
+    fn to_canonical_string(&self) -> String;
+}
+
+#[cfg(feature = "alloc")]
+impl CanonicalExt for Duration {
+    fn to_canonical_string(&self) -> String {
+        let mut remaining = self.as_nanos();
+        if remaining == 0 {
+            return String::from("0s");
+        }
+        let mut out = String::new();
+        for (unit, _, unit_nanos) in UNITS {
+            let count = remaining / unit_nanos;
+            remaining %= unit_nanos;
+            if count > 0 {
+                write!(out, "{}{}", count, unit).expect("writing to a String cannot fail");
+            }
+        }
+        out
+    }
+}
+
+/// Parses the canonical form written by [`CanonicalExt::to_canonical_string`].
+///
+/// Parsing is strict: only the exact canonical string of a duration is accepted, so
+/// whitespace, out-of-order or repeated units, zero components, leading zeros and
+/// components that should have carried into a larger unit (such as `"60s"`) are
+/// rejected with [`ParseDurationError::InvalidFormat`]. Use [`parse_duration`](crate::parse_duration)
+/// for lenient, human-written input.
+///
+/// # Errors
+///
+/// Returns a [`ParseDurationError`] describing why the input is not canonical.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{from_canonical_str, DurationExt, ParseDurationError};
+///
+/// assert_eq!(from_canonical_str("1h30m"), Ok(90.minutes()));
+/// assert_eq!(from_canonical_str("0s"), Ok(0.seconds()));
+/// assert_eq!(from_canonical_str("90m"), Err(ParseDurationError::InvalidFormat));
+/// assert_eq!(from_canonical_str("1h 30m"), Err(ParseDurationError::InvalidFormat));
+/// ```
+pub fn from_canonical_str(input: &str) -> Result<Duration, ParseDurationError> {
+    if input.is_empty() {
+        return Err(ParseDurationError::Empty);
+    }
+    if input.starts_with('-') {
+        return Err(ParseDurationError::Negative);
+    }
+    if input == "0s" {
+        return Ok(Duration::ZERO);
+    }
+
+    let mut rest = input;
+    let mut total: u128 = 0;
+    let mut next = 0;
+    while !rest.is_empty() {
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 {
+            return Err(if rest.starts_with(|c: char| c.is_ascii_alphabetic()) {
+                ParseDurationError::InvalidNumber
+            } else {
+                ParseDurationError::InvalidFormat
+            });
+        }
+        if rest.starts_with('0') {
+            return Err(ParseDurationError::InvalidFormat);
+        }
+        let count: u64 = rest[..digits].parse().map_err(|_| ParseDurationError::Overflow)?;
+
+        let letters = rest[digits..].bytes().take_while(u8::is_ascii_alphabetic).count();
+        let unit = &rest[digits..digits + letters];
+        if unit.is_empty() {
+            return Err(if digits == rest.len() {
+                ParseDurationError::MissingUnit
+            } else {
+                ParseDurationError::InvalidFormat
+            });
+        }
+        let index = UNITS
+            .iter()
+            .position(|(candidate, _, _)| *candidate == unit)
+            .ok_or(ParseDurationError::UnknownUnit)?;
+        let (_, limit, unit_nanos) = UNITS[index];
+        if index < next || limit.is_some_and(|limit| count >= limit) {
+            return Err(ParseDurationError::InvalidFormat);
+        }
+        next = index + 1;
+
+        // Weeks are the only unbounded component and come first, so this is the only
+        // place the total can overflow.
+        total += u128::from(count) * unit_nanos;
+        rest = &rest[digits + letters..];
+    }
+    crate::value::duration_from_nanos(total).ok_or(ParseDurationError::Overflow)
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_to_canonical_string() {
+        assert_eq!(Duration::ZERO.to_canonical_string(), "0s");
+        assert_eq!(90.minutes().to_canonical_string(), "1h30m");
+        assert_eq!(1.nanoseconds().to_canonical_string(), "1ns");
+        assert_eq!((7 * 24).hours().to_canonical_string(), "1w");
+        assert_eq!(1001.microseconds().to_canonical_string(), "1ms1us");
+        assert_eq!(Duration::MAX.to_canonical_string(), "30500568904943w7h15s999ms999us999ns");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_round_trip() {
+        let edges = [
+            Duration::ZERO,
+            Duration::from_nanos(1),
+            Duration::new(59, 999_999_999),
+            Duration::new(604_800, 0),
+            Duration::MAX,
+        ];
+        for duration in edges {
+            assert_eq!(from_canonical_str(&duration.to_canonical_string()), Ok(duration));
+        }
+
+        // A fixed-seed sweep over seconds and nanoseconds at every magnitude.
+        let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+        for _ in 0..10_000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let duration = Duration::new(state >> (state % 64), (state % 1_000_000_000) as u32);
+            let canonical = duration.to_canonical_string();
+            assert_eq!(from_canonical_str(&canonical), Ok(duration), "{}", canonical);
+        }
+    }
+
+    #[test]
+    fn test_from_canonical_str() {
+        assert_eq!(from_canonical_str("1w2d3h4m5s6ms7us8ns"), Ok(
+            (24 * 9 + 3).hours() + 4.minutes() + 5.seconds() + 6.milliseconds() + 7.microseconds() + 8.nanoseconds()
+        ));
+        assert_eq!(from_canonical_str("999ms"), Ok(999.milliseconds()));
+        assert_eq!(from_canonical_str("2w"), Ok((14 * 24).hours()));
+    }
+
+    #[test]
+    fn test_rejects_non_canonical() {
+        for input in ["0h", "0s1ns", "01s", "60s", "24h", "7d", "1000ms", "30m1h", "1s1s", " 1s", "1s ", "1h 30m", "1.5s"] {
+            assert_eq!(from_canonical_str(input), Err(ParseDurationError::InvalidFormat), "{:?}", input);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(from_canonical_str(""), Err(ParseDurationError::Empty));
+        assert_eq!(from_canonical_str("-1s"), Err(ParseDurationError::Negative));
+        assert_eq!(from_canonical_str("h"), Err(ParseDurationError::InvalidNumber));
+        assert_eq!(from_canonical_str("30"), Err(ParseDurationError::MissingUnit));
+        assert_eq!(from_canonical_str("30x"), Err(ParseDurationError::UnknownUnit));
+        assert_eq!(from_canonical_str("1H"), Err(ParseDurationError::UnknownUnit));
+        assert_eq!(from_canonical_str("30500568904944w"), Err(ParseDurationError::Overflow));
+        assert_eq!(from_canonical_str("99999999999999999999w"), Err(ParseDurationError::Overflow));
+    }
+}
@@ -0,0 +1,103 @@
+use core::time::Duration;
+
+/// An extension trait with comparison predicates that read well in guard clauses.
+///
+/// `if elapsed.is_longer_than(5.seconds())` states the intent more directly than
+/// `if elapsed > 5.seconds()`, and [`is_within`](Self::is_within) and
+/// [`approx_eq`](Self::approx_eq) replace chained comparisons.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationCompareExt, DurationExt};
+///
+/// let elapsed = 1500.milliseconds();
+/// assert!(elapsed.is_longer_than(1.seconds()));
+/// assert!(elapsed.is_within(1.seconds(), 10.seconds()));
+/// assert_eq!(elapsed.clamp_between(2.seconds(), 5.seconds()), 2.seconds());
+/// assert!(elapsed.approx_eq(1.seconds(), 500.milliseconds()));
+/// ```
+pub trait DurationCompareExt {
+    /// Returns `true` if this duration is strictly longer than `other`.
+    fn is_longer_than(&self, other: Duration) -> bool;
+    /// Returns `true` if this duration is strictly shorter than `other`.
+    fn is_shorter_than(&self, other: Duration) -> bool;
+    /// Returns `true` if this duration lies between `min` and `max`, both inclusive.
+    fn is_within(&self, min: Duration, max: Duration) -> bool;
+    /// Restricts this duration to the range from `min` to `max`, like `Ord::clamp`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    fn clamp_between(self, min: Duration, max: Duration) -> Duration;
+    /// Returns `true` if this duration differs from `other` by at most `tolerance`.
+    fn approx_eq(&self, other: Duration, tolerance: Duration) -> bool;
+}
+
+impl DurationCompareExt for Duration {
+    fn is_longer_than(&self, other: Duration) -> bool {
+        *self > other
+    }
+
+    fn is_shorter_than(&self, other: Duration) -> bool {
+        *self < other
+    }
+
+    fn is_within(&self, min: Duration, max: Duration) -> bool {
+        min <= *self && *self <= max
+    }
+
+    #[track_caller]
+    fn clamp_between(self, min: Duration, max: Duration) -> Duration {
+        assert!(min <= max, "clamp_between requires min <= max: got {:?} and {:?}", min, max);
+        self.clamp(min, max)
+    }
+
+    fn approx_eq(&self, other: Duration, tolerance: Duration) -> bool {
+        self.abs_diff(other) <= tolerance
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_longer_and_shorter() {
+        assert!(6.seconds().is_longer_than(5.seconds()));
+        assert!(!5.seconds().is_longer_than(5.seconds()));
+        assert!(4.seconds().is_shorter_than(5.seconds()));
+        assert!(!5.seconds().is_shorter_than(5.seconds()));
+    }
+
+    #[test]
+    fn test_is_within_is_inclusive() {
+        assert!(1.seconds().is_within(1.seconds(), 10.seconds()));
+        assert!(10.seconds().is_within(1.seconds(), 10.seconds()));
+        assert!(!11.seconds().is_within(1.seconds(), 10.seconds()));
+        assert!(!5.seconds().is_within(10.seconds(), 1.seconds()));
+    }
+
+    #[test]
+    fn test_clamp_between() {
+        assert_eq!(30.seconds().clamp_between(1.seconds(), 10.seconds()), 10.seconds());
+        assert_eq!(Duration::ZERO.clamp_between(1.seconds(), 10.seconds()), 1.seconds());
+        assert_eq!(5.seconds().clamp_between(1.seconds(), 10.seconds()), 5.seconds());
+    }
+
+    #[test]
+    #[should_panic(expected = "clamp_between requires min <= max")]
+    fn test_clamp_between_inverted_panics() {
+        let _ = 5.seconds().clamp_between(10.seconds(), 1.seconds());
+    }
+
+    #[test]
+    fn test_approx_eq() {
+        assert!(1001.milliseconds().approx_eq(1.seconds(), 1.milliseconds()));
+        assert!(999.milliseconds().approx_eq(1.seconds(), 1.milliseconds()));
+        assert!(!1002.milliseconds().approx_eq(1.seconds(), 1.milliseconds()));
+        assert!(Duration::MAX.approx_eq(Duration::ZERO, Duration::MAX));
+    }
+}
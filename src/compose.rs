@@ -0,0 +1,118 @@
+use core::time::Duration;
+
+use crate::value::IntoDurationValue;
+use crate::DurationExt;
+
+/// An extension trait that chains durations fluently: `2.hours().and(30.minutes())`.
+///
+/// # Panics
+///
+/// [`and`](Self::and) panics if the sum exceeds `Duration`'s capacity, consistent with
+/// the constructors of this crate. Use [`and_checked`](Self::and_checked) to get `None` instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationComposeExt, DurationExt};
+///
+/// let total = 2.hours().and(30.minutes()).and(15.seconds());
+/// assert_eq!(total, 2.hours() + 30.minutes() + 15.seconds());
+///
+/// assert_eq!(u64::MAX.seconds().and_checked(1.seconds()), None);
+/// ```
+pub trait DurationComposeExt {
+    /// Returns the sum of this duration and `other`.
+    fn and(self, other: Duration) -> Duration;
+    /// Returns the sum of this duration and `other`, or `None` on overflow.
+    fn and_checked(self, other: Duration) -> Option<Duration>;
+}
+
+impl DurationComposeExt for Duration {
+    #[track_caller]
+    fn and(self, other: Duration) -> Duration {
+        match self.checked_add(other) {
+            Some(sum) => sum,
+            None => panic!("duration {:?} and {:?} overflows u64 seconds capacity", self, other),
+        }
+    }
+
+    fn and_checked(self, other: Duration) -> Option<Duration> {
+        self.checked_add(other)
+    }
+}
+
+/// An extension trait for clock-style pairs such as `(2, 30).hours_minutes()`.
+///
+/// The components are simply added, so the second one may exceed 59:
+/// `(1, 90).hours_minutes()` is two and a half hours.
+///
+/// # Panics
+///
+/// Panics if either component is negative or not finite, or if either component or
+/// their sum overflows, like the corresponding [`DurationExt`] methods and
+/// [`DurationComposeExt::and`]. The panic is reported at the caller.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{ClockPairExt, DurationExt};
+///
+/// assert_eq!((2, 30).hours_minutes(), 150.minutes());
+/// assert_eq!((1, 15).minutes_seconds(), 75.seconds());
+/// ```
+pub trait ClockPairExt {
+    /// Creates a `Duration` from `(hours, minutes)`.
+    fn hours_minutes(self) -> Duration;
+    /// Creates a `Duration` from `(minutes, seconds)`.
+    fn minutes_seconds(self) -> Duration;
+}
+
+impl<A: IntoDurationValue, B: IntoDurationValue> ClockPairExt for (A, B) {
+    #[track_caller]
+    fn hours_minutes(self) -> Duration {
+        self.0.hours().and(self.1.minutes())
+    }
+
+    #[track_caller]
+    fn minutes_seconds(self) -> Duration {
+        self.0.minutes().and(self.1.seconds())
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_and_chains() {
+        assert_eq!(2.hours().and(30.minutes()), Duration::from_secs(9000));
+        assert_eq!(Duration::ZERO.and(Duration::ZERO), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_and_checked() {
+        assert_eq!(1.seconds().and_checked(500.milliseconds()), Some(Duration::from_millis(1500)));
+        assert_eq!(Duration::MAX.and_checked(1.nanoseconds()), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows u64 seconds capacity")]
+    fn test_and_panics_on_overflow() {
+        let _ = Duration::MAX.and(1.nanoseconds());
+    }
+
+    #[test]
+    fn test_clock_pairs() {
+        assert_eq!((2, 30).hours_minutes(), Duration::from_secs(9000));
+        assert_eq!((1u8, 90u64).hours_minutes(), Duration::from_secs(9000));
+        assert_eq!((0, 45).minutes_seconds(), Duration::from_secs(45));
+        assert_eq!((1.5, 0).hours_minutes(), Duration::from_secs(5400));
+    }
+
+    #[test]
+    #[should_panic(expected = "duration cannot be negative: got -30 minutes")]
+    fn test_clock_pair_negative_panics() {
+        let _ = (2, -30).hours_minutes();
+    }
+}
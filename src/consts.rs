@@ -0,0 +1,218 @@
+//! Unit constants and `const fn` constructors for `Duration`, usable where trait methods are not.
+//!
+//! Trait methods such as [`DurationExt::seconds`](crate::DurationExt::seconds) cannot be
+//! called in `const` items, so this module mirrors them as free functions taking a
+//! `u64`. The [`dur!`](crate::dur) macro builds on them for compound expressions.
+//!
+//! Overflow panics, which in a `const` item is a compile-time error.
+//!
+//! The unit constants such as [`HOUR`] read naturally in arithmetic, `3 * consts::HOUR`,
+//! and can be used as patterns. [`DAY`] and [`WEEK`] are fixed spans of 24 and 168 hours.
+//!
+//! ```rust
+//! use duration_extender::consts;
+//! use std::time::Duration;
+//!
+//! const TIMEOUT: Duration = consts::seconds(30);
+//! const CACHE_TTL: Duration = consts::hours(6);
+//!
+//! assert_eq!(TIMEOUT, Duration::from_secs(30));
+//! assert_eq!(CACHE_TTL, Duration::from_secs(6 * 3600));
+//! assert_eq!(3 * consts::HOUR + 30 * consts::MINUTE, Duration::from_secs(12_600));
+//!
+//! fn describe(interval: Duration) -> &'static str {
+//!     match interval {
+//!         consts::MINUTE => "every minute",
+//!         consts::HOUR => "hourly",
+//!         _ => "custom",
+//!     }
+//! }
+//! assert_eq!(describe(consts::minutes(60)), "hourly");
+//! ```
+
+use core::time::Duration;
+
+const SECS_PER_MINUTE: u64 = 60;
+const SECS_PER_HOUR: u64 = 60 * SECS_PER_MINUTE;
+
+/// One nanosecond.
+pub const NANOSECOND: Duration = Duration::from_nanos(1);
+/// One microsecond.
+pub const MICROSECOND: Duration = Duration::from_micros(1);
+/// One millisecond.
+pub const MILLISECOND: Duration = Duration::from_millis(1);
+/// One second.
+pub const SECOND: Duration = Duration::from_secs(1);
+/// One minute.
+pub const MINUTE: Duration = Duration::from_secs(SECS_PER_MINUTE);
+/// One hour.
+pub const HOUR: Duration = Duration::from_secs(SECS_PER_HOUR);
+/// One day, a fixed span of 24 hours.
+pub const DAY: Duration = Duration::from_secs(24 * SECS_PER_HOUR);
+/// One week, a fixed span of 168 hours.
+pub const WEEK: Duration = Duration::from_secs(7 * 24 * SECS_PER_HOUR);
+
+/// Creates a `Duration` from the specified number of hours.
+///
+/// # Panics
+///
+/// Panics if the seconds exceed `u64::MAX`.
+pub const fn hours(hours: u64) -> Duration {
+    match hours.checked_mul(SECS_PER_HOUR) {
+        Some(secs) => Duration::from_secs(secs),
+        None => panic!("duration value in hours overflows u64 seconds capacity"),
+    }
+}
+
+/// Creates a `Duration` from the specified number of minutes.
+///
+/// # Panics
+///
+/// Panics if the seconds exceed `u64::MAX`.
+pub const fn minutes(minutes: u64) -> Duration {
+    match minutes.checked_mul(SECS_PER_MINUTE) {
+        Some(secs) => Duration::from_secs(secs),
+        None => panic!("duration value in minutes overflows u64 seconds capacity"),
+    }
+}
+
+/// Creates a `Duration` from the specified number of seconds.
+pub const fn seconds(seconds: u64) -> Duration {
+    Duration::from_secs(seconds)
+}
+
+/// Creates a `Duration` from the specified number of milliseconds.
+pub const fn milliseconds(milliseconds: u64) -> Duration {
+    Duration::from_millis(milliseconds)
+}
+
+/// Creates a `Duration` from the specified number of microseconds.
+pub const fn microseconds(microseconds: u64) -> Duration {
+    Duration::from_micros(microseconds)
+}
+
+/// Creates a `Duration` from the specified number of nanoseconds.
+pub const fn nanoseconds(nanoseconds: u64) -> Duration {
+    Duration::from_nanos(nanoseconds)
+}
+
+/// Adds up the components of a [`dur!`](crate::dur) expression.
+#[doc(hidden)]
+pub const fn __sum(parts: &[Duration]) -> Duration {
+    let mut total = Duration::ZERO;
+    let mut i = 0;
+    while i < parts.len() {
+        total = match total.checked_add(parts[i]) {
+            Some(total) => total,
+            None => panic!("dur! expression overflows u64 seconds capacity"),
+        };
+        i += 1;
+    }
+    total
+}
+
+/// Builds a `Duration` from a compound expression, usable in `const` items.
+///
+/// Each component is an integer literal followed by a unit, separated by whitespace:
+/// `h`, `m`, `s`, `ms`, `us` and `ns`, or the long forms `hours`, `minutes`,
+/// `seconds`, `milliseconds`, `microseconds` and `nanoseconds`. The components are
+/// added up, and an overflow is a compile-time error in `const` context.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::dur;
+/// use std::time::Duration;
+///
+/// const TIMEOUT: Duration = dur!(30 s);
+/// const SHIFT: Duration = dur!(2 h 30 m);
+///
+/// assert_eq!(TIMEOUT, Duration::from_secs(30));
+/// assert_eq!(SHIFT, Duration::from_secs(9000));
+/// assert_eq!(dur!(1 s 500 ms), Duration::from_millis(1500));
+/// ```
+#[macro_export]
+macro_rules! dur {
+    ($($value:literal $unit:ident)+) => {
+        $crate::consts::__sum(&[$($crate::__dur_unit!($value $unit)),+])
+    };
+}
+
+/// Maps one `dur!` component to the matching `consts` constructor.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __dur_unit {
+    ($value:literal h) => { $crate::consts::hours($value) };
+    ($value:literal hours) => { $crate::consts::hours($value) };
+    ($value:literal m) => { $crate::consts::minutes($value) };
+    ($value:literal minutes) => { $crate::consts::minutes($value) };
+    ($value:literal s) => { $crate::consts::seconds($value) };
+    ($value:literal seconds) => { $crate::consts::seconds($value) };
+    ($value:literal ms) => { $crate::consts::milliseconds($value) };
+    ($value:literal milliseconds) => { $crate::consts::milliseconds($value) };
+    ($value:literal us) => { $crate::consts::microseconds($value) };
+    ($value:literal microseconds) => { $crate::consts::microseconds($value) };
+    ($value:literal ns) => { $crate::consts::nanoseconds($value) };
+    ($value:literal nanoseconds) => { $crate::consts::nanoseconds($value) };
+    ($value:literal $unit:ident) => {
+        compile_error!(concat!("unknown dur! unit `", stringify!($unit), "`, expected h, m, s, ms, us or ns"))
+    };
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    const TIMEOUT: Duration = crate::dur!(30 s);
+    const SHIFT: Duration = crate::dur!(2 h 30 m);
+    const EVERYTHING: Duration = crate::dur!(1 hours 2 minutes 3 seconds 4 milliseconds 5 microseconds 6 nanoseconds);
+
+    #[test]
+    fn test_const_fns_match_trait_methods() {
+        assert_eq!(hours(3), 3.hours());
+        assert_eq!(minutes(90), 90.minutes());
+        assert_eq!(seconds(45), 45.seconds());
+        assert_eq!(milliseconds(1500), 1500.milliseconds());
+        assert_eq!(microseconds(7), 7.microseconds());
+        assert_eq!(nanoseconds(u64::MAX), u64::MAX.nanoseconds());
+    }
+
+    #[test]
+    fn test_unit_constants() {
+        assert_eq!(NANOSECOND, 1.nanoseconds());
+        assert_eq!(MICROSECOND, 1.microseconds());
+        assert_eq!(MILLISECOND, 1.milliseconds());
+        assert_eq!(SECOND, 1.seconds());
+        assert_eq!(MINUTE, 60 * SECOND);
+        assert_eq!(HOUR, 60 * MINUTE);
+        assert_eq!(DAY, 24 * HOUR);
+        assert_eq!(WEEK, 7 * DAY);
+        assert_eq!(3 * HOUR, hours(3));
+    }
+
+    #[test]
+    fn test_dur_macro() {
+        assert_eq!(TIMEOUT, 30.seconds());
+        assert_eq!(SHIFT, 150.minutes());
+        assert_eq!(
+            EVERYTHING,
+            1.hours() + 2.minutes() + 3.seconds() + 4.milliseconds() + 5.microseconds() + 6.nanoseconds()
+        );
+        assert_eq!(crate::dur!(0 s), Duration::ZERO);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows u64 seconds capacity")]
+    fn test_hours_overflow_panics() {
+        let _ = hours(u64::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "dur! expression overflows u64 seconds capacity")]
+    fn test_dur_overflow_panics() {
+        let max = u64::MAX;
+        let _ = __sum(&[seconds(max), seconds(1)]);
+    }
+}
@@ -0,0 +1,290 @@
+use std::fmt;
+use std::time::{Duration, Instant, SystemTime};
+
+/// A point in time that a `Duration` can be added to or subtracted from.
+///
+/// This trait is sealed and implemented for `Instant` and `SystemTime`, so the
+/// [`DeadlineExt::after`] and [`DeadlineExt::before`] helpers work with both the
+/// monotonic and the wall clock.
+pub trait TimePoint: sealed::Sealed {}
+
+mod sealed {
+    use std::fmt;
+    use std::time::Duration;
+
+    pub trait Sealed: Copy + fmt::Debug {
+        fn checked_add_duration(self, duration: Duration) -> Option<Self>;
+        fn checked_sub_duration(self, duration: Duration) -> Option<Self>;
+        fn checked_duration_since(self, earlier: Self) -> Option<Duration>;
+    }
+}
+
+macro_rules! impl_time_point {
+    ($($t:ty => $duration_since:path),*) => {$(
+        impl TimePoint for $t {}
+
+        impl sealed::Sealed for $t {
+            fn checked_add_duration(self, duration: Duration) -> Option<Self> {
+                self.checked_add(duration)
+            }
+
+            fn checked_sub_duration(self, duration: Duration) -> Option<Self> {
+                self.checked_sub(duration)
+            }
+
+            fn checked_duration_since(self, earlier: Self) -> Option<Duration> {
+                $duration_since(&self, earlier)
+            }
+        }
+    )*};
+}
+
+fn system_time_since(time: &SystemTime, earlier: SystemTime) -> Option<Duration> {
+    time.duration_since(earlier).ok()
+}
+
+impl_time_point!(Instant => Instant::checked_duration_since, SystemTime => system_time_since);
+
+/// An extension trait that offsets points in time by a `Duration`, e.g. to compute a deadline.
+///
+/// [`after`](Self::after) and [`before`](Self::before) accept any [`TimePoint`]
+/// (`Instant` or `SystemTime`); [`from_now`](Self::from_now) and [`ago`](Self::ago)
+/// use the monotonic clock. Time arithmetic can overflow on some platforms, so every
+/// method has a `checked_*` counterpart that returns `None` instead of panicking.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DeadlineExt, DurationExt};
+/// use std::time::{Instant, SystemTime};
+///
+/// let deadline = 5.seconds().from_now();
+/// assert!(deadline > Instant::now());
+///
+/// let start = Instant::now();
+/// assert_eq!(3.minutes().after(start) - start, 3.minutes());
+///
+/// let issued = SystemTime::now();
+/// let expires = (30 * 24).hours().after(issued);
+/// assert_eq!(expires.duration_since(issued).unwrap(), (30 * 24).hours());
+/// ```
+pub trait DeadlineExt {
+    /// Returns the instant this duration from now.
+    // Reads as "5 seconds from now", not as a `From`-style conversion.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_now(self) -> Instant;
+    /// Returns the instant this duration before now.
+    fn ago(self) -> Instant;
+    /// Returns the point in time this duration after `time`.
+    fn after<T: TimePoint>(self, time: T) -> T;
+    /// Returns the point in time this duration before `time`.
+    fn before<T: TimePoint>(self, time: T) -> T;
+    /// Like [`from_now`](Self::from_now), but returns `None` on overflow.
+    fn checked_from_now(self) -> Option<Instant>;
+    /// Like [`ago`](Self::ago), but returns `None` on overflow.
+    fn checked_ago(self) -> Option<Instant>;
+    /// Like [`after`](Self::after), but returns `None` on overflow.
+    fn checked_after<T: TimePoint>(self, time: T) -> Option<T>;
+    /// Like [`before`](Self::before), but returns `None` on overflow.
+    fn checked_before<T: TimePoint>(self, time: T) -> Option<T>;
+}
+
+impl DeadlineExt for Duration {
+    #[track_caller]
+    fn from_now(self) -> Instant {
+        self.after(Instant::now())
+    }
+
+    #[track_caller]
+    fn ago(self) -> Instant {
+        self.before(Instant::now())
+    }
+
+    #[track_caller]
+    fn after<T: TimePoint>(self, time: T) -> T {
+        match self.checked_after(time) {
+            Some(later) => later,
+            None => out_of_range(self, "after", time),
+        }
+    }
+
+    #[track_caller]
+    fn before<T: TimePoint>(self, time: T) -> T {
+        match self.checked_before(time) {
+            Some(earlier) => earlier,
+            None => out_of_range(self, "before", time),
+        }
+    }
+
+    fn checked_from_now(self) -> Option<Instant> {
+        self.checked_after(Instant::now())
+    }
+
+    fn checked_ago(self) -> Option<Instant> {
+        self.checked_before(Instant::now())
+    }
+
+    fn checked_after<T: TimePoint>(self, time: T) -> Option<T> {
+        time.checked_add_duration(self)
+    }
+
+    fn checked_before<T: TimePoint>(self, time: T) -> Option<T> {
+        time.checked_sub_duration(self)
+    }
+}
+
+#[track_caller]
+fn out_of_range(duration: Duration, direction: &str, time: impl fmt::Debug) -> ! {
+    panic!("{:?} {} {:?} overflows the representable time range", duration, direction, time)
+}
+
+/// An extension trait for wall-clock checks on `SystemTime`, e.g. token or certificate expiry.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DeadlineExt, DurationExt, SystemTimeExt};
+/// use std::time::SystemTime;
+///
+/// let issued_at = 10.minutes().before(SystemTime::now());
+/// assert!(issued_at.elapsed_at_least(5.minutes()));
+/// assert!(!issued_at.elapsed_at_least(1.hours()));
+/// ```
+pub trait SystemTimeExt {
+    /// Returns `true` if at least `duration` has passed since this time.
+    ///
+    /// A time in the future (e.g. after the system clock was adjusted backwards)
+    /// has not elapsed at all, so this returns `false` unless `duration` is zero.
+    fn elapsed_at_least(&self, duration: Duration) -> bool;
+}
+
+impl SystemTimeExt for SystemTime {
+    fn elapsed_at_least(&self, duration: Duration) -> bool {
+        let elapsed = self.elapsed().unwrap_or(Duration::ZERO);
+        elapsed >= duration
+    }
+}
+
+/// An extension trait for elapsed-time checks on `Instant`, e.g. cache expiry.
+///
+/// `last_refresh.has_elapsed(5.minutes())` replaces
+/// `last_refresh.elapsed() >= Duration::from_secs(300)`.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DeadlineExt, DurationExt, InstantExt};
+///
+/// let last_refresh = 10.minutes().ago();
+/// assert!(last_refresh.has_elapsed(5.minutes()));
+/// assert!(last_refresh.age() >= 10.minutes());
+///
+/// let remaining = last_refresh.remaining_until_deadline(15.minutes());
+/// assert!(remaining > 4.minutes() && remaining <= 5.minutes());
+/// assert_eq!(last_refresh.remaining_until_deadline(1.minutes()), 0.seconds());
+/// ```
+pub trait InstantExt {
+    /// Returns `true` if at least `duration` has passed since this instant.
+    fn has_elapsed(&self, duration: Duration) -> bool;
+    /// Returns the time left until `duration` after this instant, or zero if that has passed.
+    fn remaining_until_deadline(&self, duration: Duration) -> Duration;
+    /// Returns how much time has passed since this instant, like `Instant::elapsed`.
+    fn age(&self) -> Duration;
+}
+
+impl InstantExt for Instant {
+    fn has_elapsed(&self, duration: Duration) -> bool {
+        self.age() >= duration
+    }
+
+    fn remaining_until_deadline(&self, duration: Duration) -> Duration {
+        duration.saturating_sub(self.age())
+    }
+
+    fn age(&self) -> Duration {
+        self.elapsed()
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_after_and_before() {
+        let start = Instant::now();
+        assert_eq!(3.minutes().after(start).duration_since(start), 3.minutes());
+        let later = 10.seconds().after(start);
+        assert_eq!(4.seconds().before(later).duration_since(start), 6.seconds());
+    }
+
+    #[test]
+    fn test_from_now_and_ago() {
+        let before = Instant::now();
+        let deadline = 1.hours().from_now();
+        assert!(deadline >= 1.hours().after(before));
+
+        let past = 1.milliseconds().ago();
+        assert!(past < Instant::now());
+    }
+
+    #[test]
+    fn test_checked_overflow_is_none() {
+        let now = Instant::now();
+        assert_eq!(Duration::MAX.checked_after(now), None);
+        assert_eq!(Duration::MAX.checked_before(now), None);
+        assert_eq!(Duration::MAX.checked_from_now(), None);
+        assert_eq!(Duration::MAX.checked_ago(), None);
+        assert_eq!(Duration::ZERO.checked_after(now), Some(now));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows the representable time range")]
+    fn test_after_panics_on_overflow() {
+        let _ = Duration::MAX.from_now();
+    }
+
+    #[test]
+    fn test_instant_has_elapsed() {
+        let past = 10.seconds().ago();
+        assert!(past.has_elapsed(10.seconds()));
+        assert!(!past.has_elapsed(1.hours()));
+        assert!(past.age() >= 10.seconds());
+        assert!(Instant::now().has_elapsed(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_instant_remaining_until_deadline() {
+        let past = 10.seconds().ago();
+        assert_eq!(past.remaining_until_deadline(5.seconds()), Duration::ZERO);
+        let remaining = past.remaining_until_deadline(1.minutes());
+        assert!(remaining <= 50.seconds() && remaining > 40.seconds());
+        assert!(Instant::now().remaining_until_deadline(Duration::MAX) > u64::MAX.seconds() - 1.hours());
+    }
+
+    // --- SystemTime Tests ---
+    #[test]
+    fn test_system_time_after_and_before() {
+        let now = SystemTime::now();
+        let expiry = (30 * 24).hours().after(now);
+        assert_eq!(expiry.duration_since(now).unwrap(), (30 * 24).hours());
+        assert_eq!(now.duration_since(5.minutes().before(now)).unwrap(), 5.minutes());
+        assert_eq!(Duration::MAX.checked_after(now), None);
+    }
+
+    #[test]
+    fn test_elapsed_at_least() {
+        let issued_at = 10.minutes().before(SystemTime::now());
+        assert!(issued_at.elapsed_at_least(5.minutes()));
+        assert!(!issued_at.elapsed_at_least(1.hours()));
+    }
+
+    #[test]
+    fn test_elapsed_at_least_future_time() {
+        let future = 1.hours().after(SystemTime::now());
+        assert!(!future.elapsed_at_least(1.seconds()));
+        assert!(future.elapsed_at_least(Duration::ZERO));
+    }
+}
@@ -0,0 +1,229 @@
+use core::fmt::{self, Write};
+use core::time::Duration;
+
+use crate::{English, Locale, Unit};
+
+const SECS_PER_MINUTE: u64 = 60;
+const SECS_PER_HOUR: u64 = 60 * SECS_PER_MINUTE;
+const SECS_PER_DAY: u64 = 24 * SECS_PER_HOUR;
+
+/// Displays a duration in short units, e.g. `"2h 30m 15s"`.
+///
+/// The same text as [`to_human_string`](crate::DurationFormatExt::to_human_string), written
+/// straight into the formatter without allocating. Durations shorter than one second
+/// keep their sub-second components.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Compact(pub Duration);
+
+/// Displays a duration with English unit names, e.g. `"2 hours 30 minutes 15 seconds"`.
+///
+/// Shows the same components as [`Compact`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Verbose(pub Duration);
+
+/// Displays a duration clock-style as `"HH:MM:SS"`, e.g. `"01:05:09"`.
+///
+/// Hours are not wrapped at 24; sub-second precision is truncated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Clock(pub Duration);
+
+impl fmt::Display for Compact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_human(f, self.0, self.0.as_secs() == 0, Labels::Short)
+    }
+}
+
+impl fmt::Display for Verbose {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_human(f, self.0, self.0.as_secs() == 0, Labels::Locale(&English))
+    }
+}
+
+impl fmt::Display for Clock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_pattern(f, self.0, "%T")
+    }
+}
+
+/// An extension trait that wraps a `Duration` for allocation-free `Display`.
+///
+/// Unlike the `String`-returning methods of `DurationFormatExt`, the wrappers write
+/// directly into `format!`, `write!` or log fields, so this also works without `alloc`.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationDisplayExt, DurationExt};
+///
+/// let elapsed = 1.hours() + 5.minutes() + 9.seconds();
+/// assert_eq!(format!("took {}", elapsed.display_compact()), "took 1h 5m 9s");
+/// assert_eq!(elapsed.display_verbose().to_string(), "1 hour 5 minutes 9 seconds");
+/// assert_eq!(elapsed.display_clock().to_string(), "01:05:09");
+/// ```
+pub trait DurationDisplayExt {
+    /// Wraps this duration to display as [`Compact`], e.g. `"2h 30m"`.
+    fn display_compact(self) -> Compact;
+    /// Wraps this duration to display as [`Verbose`], e.g. `"2 hours 30 minutes"`.
+    fn display_verbose(self) -> Verbose;
+    /// Wraps this duration to display as [`Clock`], e.g. `"02:30:00"`.
+    fn display_clock(self) -> Clock;
+}
+
+impl DurationDisplayExt for Duration {
+    fn display_compact(self) -> Compact {
+        Compact(self)
+    }
+
+    fn display_verbose(self) -> Verbose {
+        Verbose(self)
+    }
+
+    fn display_clock(self) -> Clock {
+        Clock(self)
+    }
+}
+
+/// How [`write_human`] labels each component.
+pub(crate) enum Labels<'a> {
+    /// Abbreviations without a space: `"2h 30m"`.
+    Short,
+    /// Unit names from a locale: `"2 hours 30 minutes"`.
+    Locale(&'a dyn Locale),
+}
+
+impl Labels<'_> {
+    fn write_component<W: Write>(&self, out: &mut W, value: u64, unit: Unit) -> fmt::Result {
+        match self {
+            Labels::Short => {
+                let abbreviation = match unit {
+                    Unit::Nanos => "ns",
+                    Unit::Micros => "us",
+                    Unit::Millis => "ms",
+                    Unit::Seconds => "s",
+                    Unit::Minutes => "m",
+                    Unit::Hours => "h",
+                    Unit::Days => "d",
+                    Unit::Weeks => "w",
+                };
+                write!(out, "{}{}", value, abbreviation)
+            }
+            Labels::Locale(locale) => write!(out, "{} {}", value, locale.unit_name(unit, value)),
+        }
+    }
+
+    fn separator(&self) -> &str {
+        match self {
+            Labels::Short => " ",
+            Labels::Locale(locale) => locale.separator(),
+        }
+    }
+}
+
+/// Writes the non-zero components of `duration`, separated by the separator of `labels`.
+pub(crate) fn write_human<W: Write>(out: &mut W, duration: Duration, precise: bool, labels: Labels<'_>) -> fmt::Result {
+    let secs = duration.as_secs();
+    let nanos = duration.subsec_nanos();
+
+    let components = [
+        (secs / SECS_PER_DAY, Unit::Days),
+        (secs % SECS_PER_DAY / SECS_PER_HOUR, Unit::Hours),
+        (secs % SECS_PER_HOUR / SECS_PER_MINUTE, Unit::Minutes),
+        (secs % SECS_PER_MINUTE, Unit::Seconds),
+        (u64::from(nanos / 1_000_000), Unit::Millis),
+        (u64::from(nanos / 1_000 % 1_000), Unit::Micros),
+        (u64::from(nanos % 1_000), Unit::Nanos),
+    ];
+
+    let mut written = false;
+    let shown = if precise { 7 } else { 4 };
+    for (value, unit) in components.into_iter().take(shown) {
+        if value == 0 {
+            continue;
+        }
+        if written {
+            out.write_str(labels.separator())?;
+        }
+        labels.write_component(out, value, unit)?;
+        written = true;
+    }
+
+    if !written {
+        labels.write_component(out, 0, Unit::Seconds)?;
+    }
+    Ok(())
+}
+
+/// Writes `duration` according to a pattern, see [`DurationFormatExt::format`](crate::DurationFormatExt::format).
+pub(crate) fn write_pattern<W: Write>(out: &mut W, duration: Duration, pattern: &str) -> fmt::Result {
+    let secs = duration.as_secs();
+    let mut rest = pattern;
+    while let Some(percent) = rest.find('%') {
+        out.write_str(&rest[..percent])?;
+        let directive = &rest[percent + 1..];
+
+        // Each arm returns how many bytes of the directive it consumed after the `%`.
+        let consumed = match directive.as_bytes() {
+            [b'H', ..] => {
+                write!(out, "{:02}", secs / SECS_PER_HOUR)?;
+                1
+            }
+            [b'M', ..] => {
+                write!(out, "{:02}", secs % SECS_PER_HOUR / SECS_PER_MINUTE)?;
+                1
+            }
+            [b'S', ..] => {
+                write!(out, "{:02}", secs % SECS_PER_MINUTE)?;
+                1
+            }
+            [b'T', ..] => {
+                write_pattern(out, duration, "%H:%M:%S")?;
+                1
+            }
+            [b'%', ..] => {
+                out.write_char('%')?;
+                1
+            }
+            [b'.', digits @ b'1'..=b'9', b'f', ..] => {
+                let digits = u32::from(digits - b'0');
+                let fraction = duration.subsec_nanos() / 10u32.pow(9 - digits);
+                write!(out, ".{:0width$}", fraction, width = digits as usize)?;
+                3
+            }
+            _ => {
+                out.write_char('%')?;
+                0
+            }
+        };
+        rest = &directive[consumed..];
+    }
+    out.write_str(rest)
+}
+
+// ===== Tests =====
+#[cfg(test)]
+#[cfg(feature = "alloc")]
+mod tests {
+    use super::*;
+    use crate::{DurationExt, DurationFormatExt};
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_wrappers_match_string_formatters() {
+        for duration in [Duration::ZERO, 1500.microseconds(), 2.hours() + 30.minutes() + 15.seconds(), Duration::MAX] {
+            assert_eq!(duration.display_compact().to_string(), duration.to_human_string());
+            assert_eq!(duration.display_verbose().to_string(), duration.to_human_string_with(&English));
+            assert_eq!(duration.display_clock().to_string(), duration.to_hhmmss());
+        }
+    }
+
+    #[test]
+    fn test_verbose_singular_and_plural() {
+        assert_eq!(Verbose(1.hours() + 2.minutes()).to_string(), "1 hour 2 minutes");
+        assert_eq!(Verbose(Duration::ZERO).to_string(), "0 seconds");
+    }
+
+    #[test]
+    fn test_clock_does_not_wrap_hours() {
+        assert_eq!(Clock(100.hours() + 1.seconds()).to_string(), "100:00:01");
+    }
+}
@@ -0,0 +1,208 @@
+use core::time::Duration;
+
+use crate::value::duration_from_nanos;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// An extension trait that converts a `Duration` into the raw millisecond timeouts of C APIs.
+///
+/// Calls such as `poll(2)`, `epoll_wait(2)` and `WaitForSingleObject` take an `int` or
+/// `DWORD` number of milliseconds. These conversions return `None` when the duration
+/// does not fit instead of silently truncating.
+///
+/// A partial millisecond is rounded up, so a short non-zero timeout never turns into
+/// `0`, which most of these APIs treat as "return immediately" and which would make a
+/// waiting loop spin.
+///
+/// [`to_timespec`](Self::to_timespec) and [`to_timeval`](Self::to_timeval) split a
+/// duration into the seconds and sub-second fields of `struct timespec` and `struct
+/// timeval`. They are generic over the field types, whose widths vary by platform
+/// (`time_t` is 32 bits on some targets), so the result can go straight into the `libc`
+/// structs without an unchecked `as` cast:
+///
+/// ```text
+/// let (tv_sec, tv_nsec) = timeout.to_timespec().ok_or(Error::TimeoutTooLong)?;
+/// let ts = libc::timespec { tv_sec, tv_nsec };
+/// ```
+///
+/// [`to_ticks`](Self::to_ticks) counts the ticks of a timer running at a given
+/// frequency, e.g. for a HAL delay or a `fugit::TimerDurationU32<HZ>` built with
+/// `from_ticks`; [`from_ticks`] converts back. Both round a partial tick up.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, DurationFfiExt};
+/// use std::time::Duration;
+///
+/// assert_eq!(250.milliseconds().to_millis_i32(), Some(250));
+/// assert_eq!(100.microseconds().to_millis_i32(), Some(1));
+/// assert_eq!((30 * 24).hours().to_millis_u32(), Some(2_592_000_000));
+/// assert_eq!((30 * 24).hours().to_millis_i32(), None);
+///
+/// assert_eq!(1500.milliseconds().to_timespec::<i64, i64>(), Some((1, 500_000_000)));
+/// assert_eq!(1.nanoseconds().to_timeval::<i64, i32>(), Some((0, 1)));
+/// assert_eq!(Duration::MAX.to_timespec::<i32, i32>(), None);
+///
+/// assert_eq!(500.milliseconds().to_ticks::<u32>(32_768), Some(16_384));
+/// assert_eq!(1.nanoseconds().to_ticks::<u32>(1_000), Some(1));
+/// ```
+pub trait DurationFfiExt {
+    /// Returns this duration in milliseconds, rounded up, or `None` if it exceeds `u32::MAX`.
+    fn to_millis_u32(self) -> Option<u32>;
+    /// Returns this duration in milliseconds, rounded up, or `None` if it exceeds `i32::MAX`.
+    ///
+    /// The result is never negative, so it can't be confused with the `-1` that `poll(2)`
+    /// uses for "no timeout".
+    fn to_millis_i32(self) -> Option<i32>;
+    /// Returns the `(tv_sec, tv_nsec)` fields of a `timespec`, or `None` if either does
+    /// not fit its type.
+    ///
+    /// The conversion is exact; `tv_nsec` is always below 1,000,000,000.
+    fn to_timespec<S: TryFrom<u64>, N: TryFrom<u32>>(self) -> Option<(S, N)>;
+    /// Returns the `(tv_sec, tv_usec)` fields of a `timeval`, or `None` if either does
+    /// not fit its type.
+    ///
+    /// A partial microsecond is rounded up, carrying into `tv_sec` when needed;
+    /// `tv_usec` is always below 1,000,000.
+    fn to_timeval<S: TryFrom<u64>, U: TryFrom<u32>>(self) -> Option<(S, U)>;
+    /// Returns the number of ticks of a `hz` timer in this duration, rounded up, or `None`
+    /// if `hz` is zero or the count does not fit `T`.
+    fn to_ticks<T: TryFrom<u128>>(self, hz: u32) -> Option<T>;
+}
+
+impl DurationFfiExt for Duration {
+    fn to_millis_u32(self) -> Option<u32> {
+        u32::try_from(millis_rounded_up(self)).ok()
+    }
+
+    fn to_millis_i32(self) -> Option<i32> {
+        i32::try_from(millis_rounded_up(self)).ok()
+    }
+
+    fn to_timespec<S: TryFrom<u64>, N: TryFrom<u32>>(self) -> Option<(S, N)> {
+        let secs = S::try_from(self.as_secs()).ok()?;
+        let nanos = N::try_from(self.subsec_nanos()).ok()?;
+        Some((secs, nanos))
+    }
+
+    fn to_timeval<S: TryFrom<u64>, U: TryFrom<u32>>(self) -> Option<(S, U)> {
+        let mut secs = self.as_secs();
+        let mut micros = self.subsec_nanos().div_ceil(1_000);
+        if micros == 1_000_000 {
+            secs = secs.checked_add(1)?;
+            micros = 0;
+        }
+        Some((S::try_from(secs).ok()?, U::try_from(micros).ok()?))
+    }
+
+    fn to_ticks<T: TryFrom<u128>>(self, hz: u32) -> Option<T> {
+        if hz == 0 {
+            return None;
+        }
+        // Below 2^94 nanoseconds times a `u32` frequency, so this can't overflow `u128`.
+        let ticks = (self.as_nanos() * u128::from(hz)).div_ceil(NANOS_PER_SEC);
+        T::try_from(ticks).ok()
+    }
+}
+
+/// Returns the duration of `ticks` ticks of a `hz` timer, rounded up to the next
+/// nanosecond, or `None` if `hz` is zero.
+///
+/// The inverse of [`DurationFfiExt::to_ticks`], e.g. for the tick count of a
+/// `fugit::TimerDurationU32<HZ>`.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{from_ticks, DurationExt};
+///
+/// assert_eq!(from_ticks(16_384, 32_768), Some(500.milliseconds()));
+/// assert_eq!(from_ticks(1, 3), Some(333_333_334.nanoseconds()));
+/// assert_eq!(from_ticks(1, 0), None);
+/// ```
+pub fn from_ticks(ticks: u64, hz: u32) -> Option<Duration> {
+    if hz == 0 {
+        return None;
+    }
+    // At most `u64::MAX` seconds, which always fits.
+    duration_from_nanos((u128::from(ticks) * NANOS_PER_SEC).div_ceil(u128::from(hz)))
+}
+
+fn millis_rounded_up(duration: Duration) -> u128 {
+    duration.as_nanos().div_ceil(1_000_000)
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_exact_millis() {
+        assert_eq!(Duration::ZERO.to_millis_u32(), Some(0));
+        assert_eq!(1500.milliseconds().to_millis_u32(), Some(1500));
+        assert_eq!(1500.milliseconds().to_millis_i32(), Some(1500));
+    }
+
+    #[test]
+    fn test_partial_millis_round_up() {
+        assert_eq!(1.nanoseconds().to_millis_i32(), Some(1));
+        assert_eq!((2.milliseconds() + 1.nanoseconds()).to_millis_u32(), Some(3));
+    }
+
+    #[test]
+    fn test_limits() {
+        assert_eq!((i32::MAX as u64).milliseconds().to_millis_i32(), Some(i32::MAX));
+        assert_eq!((i32::MAX as u64 + 1).milliseconds().to_millis_i32(), None);
+        assert_eq!((u32::MAX as u64).milliseconds().to_millis_u32(), Some(u32::MAX));
+        assert_eq!(((u32::MAX as u64).milliseconds() + 1.nanoseconds()).to_millis_u32(), None);
+        assert_eq!(Duration::MAX.to_millis_u32(), None);
+    }
+
+    #[test]
+    fn test_timespec() {
+        assert_eq!(Duration::ZERO.to_timespec::<i64, i64>(), Some((0, 0)));
+        assert_eq!((2.seconds() + 7.nanoseconds()).to_timespec::<i64, i64>(), Some((2, 7)));
+        assert_eq!(Duration::MAX.to_timespec::<u64, u32>(), Some((u64::MAX, 999_999_999)));
+        assert_eq!((i32::MAX as u64).seconds().to_timespec::<i32, i32>(), Some((i32::MAX, 0)));
+        assert_eq!((i32::MAX as u64 + 1).seconds().to_timespec::<i32, i32>(), None);
+        assert_eq!(Duration::MAX.to_timespec::<i64, i64>(), None);
+    }
+
+    #[test]
+    fn test_ticks() {
+        assert_eq!(Duration::ZERO.to_ticks::<u32>(1_000), Some(0));
+        assert_eq!(1.seconds().to_ticks::<u64>(1_000_000), Some(1_000_000));
+        assert_eq!((1.milliseconds() + 1.nanoseconds()).to_ticks::<u32>(1_000), Some(2));
+        assert_eq!(1.seconds().to_ticks::<u32>(0), None);
+        assert_eq!(Duration::from_secs(u64::from(u32::MAX)).to_ticks::<u32>(1), Some(u32::MAX));
+        assert_eq!(Duration::from_secs(u64::from(u32::MAX) + 1).to_ticks::<u32>(1), None);
+        assert!(Duration::MAX.to_ticks::<u128>(u32::MAX).is_some());
+    }
+
+    #[test]
+    fn test_from_ticks() {
+        assert_eq!(from_ticks(0, 1_000), Some(Duration::ZERO));
+        assert_eq!(from_ticks(1_500, 1_000), Some(1500.milliseconds()));
+        assert_eq!(from_ticks(u64::MAX, 1), Some(Duration::from_secs(u64::MAX)));
+        assert_eq!(from_ticks(7, 0), None);
+        for duration in [1.nanoseconds(), 333.milliseconds(), 2.hours()] {
+            let ticks = duration.to_ticks::<u64>(32_768).unwrap();
+            assert!(from_ticks(ticks, 32_768).unwrap() >= duration);
+        }
+    }
+
+    #[test]
+    fn test_timeval() {
+        assert_eq!(1500.milliseconds().to_timeval::<i64, i64>(), Some((1, 500_000)));
+        assert_eq!((3.seconds() + 1.nanoseconds()).to_timeval::<i64, i32>(), Some((3, 1)));
+        assert_eq!(
+            (3.seconds() + 999_999_001.nanoseconds()).to_timeval::<i64, i32>(),
+            Some((4, 0))
+        );
+        assert_eq!((i32::MAX as u64 + 1).seconds().to_timeval::<i32, i32>(), None);
+        assert_eq!(Duration::MAX.to_timeval::<u64, u32>(), None);
+    }
+}
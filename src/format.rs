@@ -0,0 +1,250 @@
+use alloc::string::String;
+use core::fmt::Write;
+use core::time::Duration;
+
+use crate::display::{write_human, write_pattern, Labels};
+use crate::{Humanizer, Locale};
+
+const SECS_PER_MINUTE: u64 = 60;
+
+/// An extension trait that renders a `Duration` as a short, human-readable string.
+///
+/// The output uses the same unit vocabulary as the fluent constructors, so
+/// `(2.hours() + 30.minutes() + 15.seconds()).to_human_string()` reads back as `"2h 30m 15s"`.
+///
+/// Days are written as **fixed 24-hour spans** (`d`). They are a display convenience
+/// only and carry no calendar meaning (see the notes on `DurationExt`).
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, DurationFormatExt};
+///
+/// let total = 2.hours() + 30.minutes() + 15.seconds();
+/// assert_eq!(total.to_human_string(), "2h 30m 15s");
+///
+/// let precise = 1.seconds() + 250.milliseconds() + 3.microseconds();
+/// assert_eq!(precise.to_human_string(), "1s");
+/// assert_eq!(precise.to_human_string_precise(), "1s 250ms 3us");
+///
+/// let track = 3.minutes() + 7.seconds() + 450.milliseconds();
+/// assert_eq!(track.to_hhmmss(), "00:03:07");
+/// assert_eq!(track.to_mmss_millis(), "03:07.450");
+/// assert_eq!(track.format("%M:%S%.1f"), "03:07.4");
+/// ```
+pub trait DurationFormatExt {
+    /// Formats the whole days, hours, minutes and seconds of this duration, e.g. `"2h 30m 15s"`.
+    ///
+    /// Sub-second precision is dropped, except for durations shorter than one second,
+    /// which fall back to the precise form so they never render as `"0s"`.
+    fn to_human_string(&self) -> String;
+
+    /// Formats every non-zero component down to nanoseconds, e.g. `"1s 250ms 3us"`.
+    fn to_human_string_precise(&self) -> String;
+
+    /// Like [`to_human_string`](Self::to_human_string), but with unit names from `locale`,
+    /// e.g. `"2 hours 30 minutes"` with [`English`](crate::English).
+    fn to_human_string_with<L: Locale + ?Sized>(&self, locale: &L) -> String;
+
+    /// Like [`to_human_string_precise`](Self::to_human_string_precise), but with unit
+    /// names from `locale`.
+    fn to_human_string_precise_with<L: Locale + ?Sized>(&self, locale: &L) -> String;
+
+    /// Formats this duration clock-style as `"HH:MM:SS"`, e.g. `"01:05:09"`.
+    ///
+    /// Hours are not wrapped at 24 and grow beyond two digits as needed; sub-second
+    /// precision is truncated.
+    fn to_hhmmss(&self) -> String;
+
+    /// Formats this duration as `"MM:SS.mmm"`, e.g. `"03:07.450"` for media timestamps.
+    ///
+    /// Minutes are not wrapped at 60 and grow beyond two digits as needed.
+    fn to_mmss_millis(&self) -> String;
+
+    /// Formats this duration with a `strftime`-like pattern, e.g. `"%H:%M:%S%.3f"`.
+    ///
+    /// | Directive | Output |
+    /// |-----------|--------|
+    /// | `%H` | whole hours, at least two digits, not wrapped at 24 |
+    /// | `%M` | minutes within the hour (`00`-`59`) |
+    /// | `%S` | seconds within the minute (`00`-`59`) |
+    /// | `%T` | same as `%H:%M:%S` |
+    /// | `%.Nf` | a dot and the first `N` (1-9) fractional digits of the second, truncated |
+    /// | `%%` | a literal `%` |
+    ///
+    /// Anything else, including unknown directives, is copied to the output unchanged.
+    fn format(&self, pattern: &str) -> String;
+
+    /// Formats this duration approximately in its most significant unit, e.g. `"about 2 hours"`.
+    ///
+    /// Uses the default thresholds of [`Humanizer`], which also offers relative forms
+    /// such as `"in 3 days"` and `"5 minutes ago"`.
+    fn humanize_approx(&self) -> String;
+}
+
+impl DurationFormatExt for Duration {
+    fn to_human_string(&self) -> String {
+        let precise = self.as_secs() == 0;
+        let mut out = String::new();
+        write_human(&mut out, *self, precise, Labels::Short).expect("writing to a String cannot fail");
+        out
+    }
+
+    fn to_human_string_precise(&self) -> String {
+        let mut out = String::new();
+        write_human(&mut out, *self, true, Labels::Short).expect("writing to a String cannot fail");
+        out
+    }
+
+    fn to_human_string_with<L: Locale + ?Sized>(&self, locale: &L) -> String {
+        let precise = self.as_secs() == 0;
+        let mut out = String::new();
+        write_human(&mut out, *self, precise, Labels::Locale(&locale)).expect("writing to a String cannot fail");
+        out
+    }
+
+    fn to_human_string_precise_with<L: Locale + ?Sized>(&self, locale: &L) -> String {
+        let mut out = String::new();
+        write_human(&mut out, *self, true, Labels::Locale(&locale)).expect("writing to a String cannot fail");
+        out
+    }
+
+    fn to_hhmmss(&self) -> String {
+        self.format("%T")
+    }
+
+    fn to_mmss_millis(&self) -> String {
+        let (secs, millis) = (self.as_secs(), self.subsec_millis());
+        let mut out = String::new();
+        write!(out, "{:02}:{:02}.{:03}", secs / SECS_PER_MINUTE, secs % SECS_PER_MINUTE, millis)
+            .expect("writing to a String cannot fail");
+        out
+    }
+
+    fn format(&self, pattern: &str) -> String {
+        let mut out = String::new();
+        write_pattern(&mut out, *self, pattern).expect("writing to a String cannot fail");
+        out
+    }
+
+    fn humanize_approx(&self) -> String {
+        Humanizer::new().format(*self)
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DurationExt, English, UnitNames};
+
+    #[test]
+    fn test_human_string_components() {
+        let total = 2.hours() + 30.minutes() + 15.seconds();
+        assert_eq!(total.to_human_string(), "2h 30m 15s");
+        assert_eq!(90.seconds().to_human_string(), "1m 30s");
+        assert_eq!(3.hours().to_human_string(), "3h");
+    }
+
+    #[test]
+    fn test_human_string_days_are_fixed_spans() {
+        assert_eq!((2 * 24 + 6).hours().to_human_string(), "2d 6h");
+    }
+
+    #[test]
+    fn test_human_string_drops_subseconds() {
+        let value = 5.seconds() + 250.milliseconds();
+        assert_eq!(value.to_human_string(), "5s");
+    }
+
+    #[test]
+    fn test_human_string_below_one_second() {
+        assert_eq!(500.milliseconds().to_human_string(), "500ms");
+        assert_eq!(1_500.microseconds().to_human_string(), "1ms 500us");
+    }
+
+    #[test]
+    fn test_human_string_zero() {
+        assert_eq!(Duration::ZERO.to_human_string(), "0s");
+        assert_eq!(Duration::ZERO.to_human_string_precise(), "0s");
+    }
+
+    #[test]
+    fn test_human_string_precise() {
+        let value = 1.hours() + 2.seconds() + 3.milliseconds() + 4.microseconds() + 5.nanoseconds();
+        assert_eq!(value.to_human_string_precise(), "1h 2s 3ms 4us 5ns");
+    }
+
+    #[test]
+    fn test_human_string_max() {
+        assert_eq!(
+            Duration::MAX.to_human_string_precise(),
+            "213503982334601d 7h 15s 999ms 999us 999ns"
+        );
+    }
+
+    // --- Clock-style Tests ---
+    #[test]
+    fn test_to_hhmmss() {
+        assert_eq!((1.hours() + 5.minutes() + 9.seconds()).to_hhmmss(), "01:05:09");
+        assert_eq!(Duration::ZERO.to_hhmmss(), "00:00:00");
+        assert_eq!((100.hours() + 999.milliseconds()).to_hhmmss(), "100:00:00");
+    }
+
+    #[test]
+    fn test_to_mmss_millis() {
+        assert_eq!((3.minutes() + 7.seconds() + 450.milliseconds()).to_mmss_millis(), "03:07.450");
+        assert_eq!((2.hours() + 5.milliseconds()).to_mmss_millis(), "120:00.005");
+    }
+
+    #[test]
+    fn test_format_pattern() {
+        let value = 26.hours() + 3.minutes() + 4.seconds() + 56_789.microseconds();
+        assert_eq!(value.format("%H:%M:%S%.3f"), "26:03:04.056");
+        assert_eq!(value.format("%T"), "26:03:04");
+        assert_eq!(value.format("%M:%S%.9f"), "03:04.056789000");
+        assert_eq!(value.format("%Hh %Mm"), "26h 03m");
+    }
+
+    #[test]
+    fn test_format_literals_and_unknown_directives() {
+        assert_eq!(1.seconds().format("100%% done in %Ss"), "100% done in 01s");
+        assert_eq!(1.seconds().format("%Q %.0f %"), "%Q %.0f %");
+        assert_eq!(1.seconds().format("plain"), "plain");
+    }
+
+    #[test]
+    fn test_humanize_approx() {
+        assert_eq!(2.seconds().humanize_approx(), "just now");
+        assert_eq!((2.hours() + 10.minutes()).humanize_approx(), "about 2 hours");
+    }
+
+    // --- Locale Tests ---
+    #[test]
+    fn test_human_string_with_english() {
+        let total = 2.hours() + 30.minutes() + 1.seconds();
+        assert_eq!(total.to_human_string_with(&English), "2 hours 30 minutes 1 second");
+        assert_eq!(Duration::ZERO.to_human_string_with(&English), "0 seconds");
+        assert_eq!(1500.microseconds().to_human_string_with(&English), "1 millisecond 500 microseconds");
+    }
+
+    #[test]
+    fn test_human_string_with_unit_names() {
+        const GERMAN: UnitNames = UnitNames::new(
+            ["Nanosekunde", "Mikrosekunde", "Millisekunde", "Sekunde", "Minute", "Stunde", "Tag", "Woche"],
+            ["Nanosekunden", "Mikrosekunden", "Millisekunden", "Sekunden", "Minuten", "Stunden", "Tage", "Wochen"],
+        );
+        let value = 26.hours() + 5.milliseconds();
+        assert_eq!(value.to_human_string_with(&GERMAN), "1 Tag 2 Stunden");
+        assert_eq!(
+            value.to_human_string_precise_with(&GERMAN.with_separator(", ")),
+            "1 Tag, 2 Stunden, 5 Millisekunden"
+        );
+    }
+
+    #[test]
+    fn test_human_string_with_dyn_locale() {
+        let locale: &dyn Locale = &English;
+        assert_eq!(2.minutes().to_human_string_with(locale), "2 minutes");
+    }
+}
@@ -0,0 +1,141 @@
+use core::time::Duration;
+
+use crate::value::{self, IntoDurationValue, NANOS_PER_HOUR, NANOS_PER_MINUTE, NANOS_PER_SEC};
+
+/// An extension trait that turns a frequency into the period between two events.
+///
+/// Game loops and polling intervals read better as rates: `60.hz()` instead of
+/// `Duration::from_nanos(1_000_000_000 / 60)`.
+///
+/// # Rounding
+///
+/// Periods are rounded to the nearest nanosecond, with ties rounding up:
+/// `60.hz()` is 16,666,667ns and `3.per_second()` is 333,333,333ns. Integer rates are
+/// exact up to that rounding; float rates go through `f64`. Rates above 2 GHz have a
+/// period below half a nanosecond and round to `Duration::ZERO`.
+///
+/// # Panics
+///
+/// Panics if the rate is zero, negative, NaN or infinite, or if a tiny float rate has a
+/// period beyond `Duration`'s capacity. Every method has a `checked_*` counterpart that
+/// returns `None` instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, RateExt};
+///
+/// assert_eq!(60.hz(), 16_666_667.nanoseconds());
+/// assert_eq!(4.per_minute(), 15.seconds());
+/// assert_eq!(0.5.per_second(), 2.seconds());
+/// assert_eq!(0.checked_hz(), None);
+/// ```
+pub trait RateExt {
+    /// Returns the period of this many events per second, an alias of [`per_second`](Self::per_second).
+    fn hz(self) -> Duration;
+    /// Returns the period of this many events per second.
+    fn per_second(self) -> Duration;
+    /// Returns the period of this many events per minute.
+    fn per_minute(self) -> Duration;
+    /// Returns the period of this many events per hour.
+    fn per_hour(self) -> Duration;
+    /// Like [`hz`](Self::hz), but returns `None` instead of panicking.
+    fn checked_hz(self) -> Option<Duration>;
+    /// Like [`per_second`](Self::per_second), but returns `None` instead of panicking.
+    fn checked_per_second(self) -> Option<Duration>;
+    /// Like [`per_minute`](Self::per_minute), but returns `None` instead of panicking.
+    fn checked_per_minute(self) -> Option<Duration>;
+    /// Like [`per_hour`](Self::per_hour), but returns `None` instead of panicking.
+    fn checked_per_hour(self) -> Option<Duration>;
+}
+
+impl<T: IntoDurationValue> RateExt for T {
+    #[track_caller]
+    fn hz(self) -> Duration {
+        value::to_period(self, NANOS_PER_SEC, "hz")
+    }
+
+    #[track_caller]
+    fn per_second(self) -> Duration {
+        value::to_period(self, NANOS_PER_SEC, "per second")
+    }
+
+    #[track_caller]
+    fn per_minute(self) -> Duration {
+        value::to_period(self, NANOS_PER_MINUTE, "per minute")
+    }
+
+    #[track_caller]
+    fn per_hour(self) -> Duration {
+        value::to_period(self, NANOS_PER_HOUR, "per hour")
+    }
+
+    fn checked_hz(self) -> Option<Duration> {
+        self.checked_period(NANOS_PER_SEC).ok()
+    }
+
+    fn checked_per_second(self) -> Option<Duration> {
+        self.checked_period(NANOS_PER_SEC).ok()
+    }
+
+    fn checked_per_minute(self) -> Option<Duration> {
+        self.checked_period(NANOS_PER_MINUTE).ok()
+    }
+
+    fn checked_per_hour(self) -> Option<Duration> {
+        self.checked_period(NANOS_PER_HOUR).ok()
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_integer_rates() {
+        assert_eq!(1.hz(), 1.seconds());
+        assert_eq!(1000.per_second(), 1.milliseconds());
+        assert_eq!(4.per_minute(), 15.seconds());
+        assert_eq!(12u8.per_hour(), 5.minutes());
+        assert_eq!(u128::MAX.hz(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_rounding_to_nearest_nanosecond() {
+        assert_eq!(60.hz(), Duration::from_nanos(16_666_667));
+        assert_eq!(3.per_second(), Duration::from_nanos(333_333_333));
+        assert_eq!(2_000_000_000u64.hz(), Duration::from_nanos(1));
+        assert_eq!(2_000_000_001u64.hz(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_float_rates() {
+        assert_eq!(0.5.hz(), 2.seconds());
+        assert_eq!(60.0.hz(), Duration::from_nanos(16_666_667));
+        assert_eq!(0.25f32.per_minute(), 4.minutes());
+        assert_eq!(1e-30.checked_hz(), None);
+    }
+
+    #[test]
+    fn test_checked_rejects_invalid_rates() {
+        assert_eq!(0.checked_hz(), None);
+        assert_eq!(0.0.checked_per_minute(), None);
+        assert_eq!((-1).checked_per_second(), None);
+        assert_eq!(f64::NAN.checked_per_hour(), None);
+        assert_eq!(2.checked_per_second(), Some(500.milliseconds()));
+    }
+
+    #[test]
+    #[should_panic(expected = "rate cannot be zero: got 0 hz")]
+    fn test_zero_rate_panics() {
+        let _ = 0.hz();
+    }
+
+    #[test]
+    #[should_panic(expected = "rate cannot be negative: got -4 per minute")]
+    fn test_negative_rate_panics() {
+        let _ = (-4).per_minute();
+    }
+}
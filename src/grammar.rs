@@ -0,0 +1,145 @@
+//! The duration string grammar shared by `parse_duration` and the `duration!` macro.
+//!
+//! The `duration-extender-macros` crate compiles this file too, through a `#[path]`
+//! module, so it may only use `core` and must not refer to items of either crate.
+
+pub(crate) const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/// The largest number of fractional digits that affect the result.
+///
+/// Even for weeks (the largest unit) digits beyond this point are below one nanosecond.
+const MAX_FRACTION_DIGITS: usize = 18;
+
+/// Why a duration string was rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum GrammarError {
+    Empty,
+    InvalidNumber,
+    MissingUnit,
+    UnknownUnit,
+    Negative,
+    Overflow,
+}
+
+impl GrammarError {
+    /// Returns the message shown to users, by `ParseDurationError` and by the macro.
+    pub(crate) const fn message(self) -> &'static str {
+        match self {
+            GrammarError::Empty => "duration string is empty",
+            GrammarError::InvalidNumber => "invalid number in duration string",
+            GrammarError::MissingUnit => "missing unit in duration string",
+            GrammarError::UnknownUnit => "unknown unit in duration string",
+            GrammarError::Negative => "duration cannot be negative",
+            GrammarError::Overflow => "duration string overflows u64 seconds capacity",
+        }
+    }
+}
+
+/// Parses a duration string such as `"1h 30m"` into whole seconds and sub-second nanoseconds.
+pub(crate) fn parse(input: &str) -> Result<(u64, u32), GrammarError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(GrammarError::Empty);
+    }
+    if input.starts_with('-') {
+        return Err(GrammarError::Negative);
+    }
+    if input == "0" {
+        return Ok((0, 0));
+    }
+
+    let mut rest = input;
+    let mut total: u128 = 0;
+    while !rest.is_empty() {
+        let (number, after_number) = split_number(rest)?;
+        let after_number = after_number.trim_start();
+        let unit_len = after_number
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(after_number.len());
+        if unit_len == 0 {
+            return Err(GrammarError::MissingUnit);
+        }
+
+        let unit_nanos = unit_nanos(&after_number[..unit_len]).ok_or(GrammarError::UnknownUnit)?;
+        let nanos = number.to_nanos(unit_nanos).ok_or(GrammarError::Overflow)?;
+        total = total.checked_add(nanos).ok_or(GrammarError::Overflow)?;
+        rest = after_number[unit_len..].trim_start();
+    }
+
+    split_total(total)
+}
+
+/// Splits a total number of nanoseconds into seconds and nanoseconds, checking that the
+/// seconds fit a `Duration`.
+pub(crate) fn split_total(total: u128) -> Result<(u64, u32), GrammarError> {
+    let secs = u64::try_from(total / NANOS_PER_SEC).map_err(|_| GrammarError::Overflow)?;
+    Ok((secs, (total % NANOS_PER_SEC) as u32))
+}
+
+/// A non-negative decimal number split into its integer and fractional parts.
+pub(crate) struct Number {
+    integer: u128,
+    fraction: u128,
+    fraction_scale: u128,
+}
+
+impl Number {
+    /// Scales this number by the length of a unit in nanoseconds, truncating below one nanosecond.
+    pub(crate) fn to_nanos(&self, unit_nanos: u128) -> Option<u128> {
+        let whole = self.integer.checked_mul(unit_nanos)?;
+        whole.checked_add(self.fraction * unit_nanos / self.fraction_scale)
+    }
+}
+
+/// Splits a leading decimal number off `input`.
+pub(crate) fn split_number(input: &str) -> Result<(Number, &str), GrammarError> {
+    let int_len = input.find(|c: char| !c.is_ascii_digit()).unwrap_or(input.len());
+    let (int_digits, rest) = input.split_at(int_len);
+
+    let (frac_digits, rest) = match rest.strip_prefix('.') {
+        Some(after_dot) => {
+            let frac_len = after_dot.find(|c: char| !c.is_ascii_digit()).unwrap_or(after_dot.len());
+            if frac_len == 0 {
+                return Err(GrammarError::InvalidNumber);
+            }
+            after_dot.split_at(frac_len)
+        }
+        None => ("", rest),
+    };
+    if int_digits.is_empty() && frac_digits.is_empty() {
+        return Err(GrammarError::InvalidNumber);
+    }
+
+    let mut integer: u128 = 0;
+    for digit in int_digits.bytes() {
+        integer = integer
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(u128::from(digit - b'0')))
+            .ok_or(GrammarError::Overflow)?;
+    }
+
+    let mut fraction: u128 = 0;
+    let mut fraction_scale: u128 = 1;
+    for digit in frac_digits.bytes().take(MAX_FRACTION_DIGITS) {
+        fraction = fraction * 10 + u128::from(digit - b'0');
+        fraction_scale *= 10;
+    }
+
+    Ok((Number { integer, fraction, fraction_scale }, rest))
+}
+
+/// Returns the length of a unit in nanoseconds, or `None` if the unit is not recognized.
+fn unit_nanos(unit: &str) -> Option<u128> {
+    let nanos = match unit {
+        "ns" | "nsec" | "nanos" => 1,
+        "us" | "µs" | "μs" | "usec" | "micros" => 1_000,
+        "ms" | "msec" | "millis" => 1_000_000,
+        "s" | "sec" | "secs" | "second" | "seconds" => NANOS_PER_SEC,
+        "m" | "min" | "mins" | "minute" | "minutes" => 60 * NANOS_PER_SEC,
+        "h" | "hr" | "hrs" | "hour" | "hours" => 3_600 * NANOS_PER_SEC,
+        "d" | "day" | "days" => 86_400 * NANOS_PER_SEC,
+        "w" | "week" | "weeks" => 604_800 * NANOS_PER_SEC,
+        _ => return None,
+    };
+    Some(nanos)
+}
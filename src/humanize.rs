@@ -0,0 +1,237 @@
+use alloc::string::String;
+use core::fmt::{self, Write};
+use core::time::Duration;
+
+use crate::SignedDuration;
+
+const SECS_PER_MINUTE: u64 = 60;
+const SECS_PER_HOUR: u64 = 60 * SECS_PER_MINUTE;
+const SECS_PER_DAY: u64 = 24 * SECS_PER_HOUR;
+const SECS_PER_WEEK: u64 = 7 * SECS_PER_DAY;
+
+/// Renders durations approximately, rounded to their most significant unit, for UI text
+/// such as relative timestamps: `"just now"`, `"about 2 hours"`, `"in 3 days"`.
+///
+/// The thresholds decide which unit is used. A duration below
+/// [`minutes_from`](Self::minutes_from) is shown in seconds, below
+/// [`hours_from`](Self::hours_from) in minutes, and so on up to weeks. The count is
+/// rounded to the nearest whole unit (at least one), and `"about"` marks a count that
+/// was rounded. Days and weeks are fixed spans of 24 and 168 hours; there are no months
+/// or years, since those have no fixed length.
+///
+/// | Threshold | Default |
+/// |-----------|---------|
+/// | [`just_now_below`](Self::just_now_below) | 10 seconds |
+/// | [`minutes_from`](Self::minutes_from) | 45 seconds |
+/// | [`hours_from`](Self::hours_from) | 45 minutes |
+/// | [`days_from`](Self::days_from) | 22 hours |
+/// | [`weeks_from`](Self::weeks_from) | 7 days |
+///
+/// For the precise component breakdown see [`DurationFormatExt`](crate::DurationFormatExt).
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, Humanizer};
+///
+/// let humanizer = Humanizer::new();
+/// assert_eq!(humanizer.format(3.seconds()), "just now");
+/// assert_eq!(humanizer.format(2.hours() + 10.minutes()), "about 2 hours");
+/// assert_eq!(humanizer.format_future((3 * 24).hours()), "in 3 days");
+/// assert_eq!(humanizer.format_past(40.minutes()), "40 minutes ago");
+///
+/// let eager = Humanizer::new().hours_from(30.minutes());
+/// assert_eq!(eager.format(40.minutes()), "about 1 hour");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Humanizer {
+    just_now_below: Duration,
+    minutes_from: Duration,
+    hours_from: Duration,
+    days_from: Duration,
+    weeks_from: Duration,
+}
+
+/// Which way in time a humanized duration points.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    None,
+    Future,
+    Past,
+}
+
+impl Humanizer {
+    /// Creates a humanizer with the default thresholds.
+    pub const fn new() -> Self {
+        Humanizer {
+            just_now_below: Duration::from_secs(10),
+            minutes_from: Duration::from_secs(45),
+            hours_from: Duration::from_secs(45 * SECS_PER_MINUTE),
+            days_from: Duration::from_secs(22 * SECS_PER_HOUR),
+            weeks_from: Duration::from_secs(SECS_PER_WEEK),
+        }
+    }
+
+    /// Durations below this render as `"just now"`. Use `Duration::ZERO` to disable it.
+    pub const fn just_now_below(mut self, threshold: Duration) -> Self {
+        self.just_now_below = threshold;
+        self
+    }
+
+    /// Durations from this length on are shown in minutes.
+    pub const fn minutes_from(mut self, threshold: Duration) -> Self {
+        self.minutes_from = threshold;
+        self
+    }
+
+    /// Durations from this length on are shown in hours.
+    pub const fn hours_from(mut self, threshold: Duration) -> Self {
+        self.hours_from = threshold;
+        self
+    }
+
+    /// Durations from this length on are shown in days.
+    pub const fn days_from(mut self, threshold: Duration) -> Self {
+        self.days_from = threshold;
+        self
+    }
+
+    /// Durations from this length on are shown in weeks.
+    pub const fn weeks_from(mut self, threshold: Duration) -> Self {
+        self.weeks_from = threshold;
+        self
+    }
+
+    /// Formats `duration` without a direction, e.g. `"about 2 hours"`.
+    pub fn format(&self, duration: Duration) -> String {
+        self.render(duration, Direction::None)
+    }
+
+    /// Formats `duration` as a point in the future, e.g. `"in about 2 hours"`.
+    pub fn format_future(&self, duration: Duration) -> String {
+        self.render(duration, Direction::Future)
+    }
+
+    /// Formats `duration` as a point in the past, e.g. `"about 2 hours ago"`.
+    pub fn format_past(&self, duration: Duration) -> String {
+        self.render(duration, Direction::Past)
+    }
+
+    /// Formats a signed offset from now: negative spans are in the past, e.g. the
+    /// result of [`SignedDuration::between`] with now as the start.
+    pub fn format_relative(&self, duration: SignedDuration) -> String {
+        let direction = if duration.is_negative() { Direction::Past } else { Direction::Future };
+        self.render(duration.unsigned_abs(), direction)
+    }
+
+    fn render(&self, duration: Duration, direction: Direction) -> String {
+        let mut out = String::new();
+        self.write(&mut out, duration, direction).expect("writing to a String cannot fail");
+        out
+    }
+
+    fn write<W: Write>(&self, out: &mut W, duration: Duration, direction: Direction) -> fmt::Result {
+        if duration < self.just_now_below {
+            return out.write_str("just now");
+        }
+
+        let (unit_secs, singular, plural) = if duration < self.minutes_from {
+            (1, "second", "seconds")
+        } else if duration < self.hours_from {
+            (SECS_PER_MINUTE, "minute", "minutes")
+        } else if duration < self.days_from {
+            (SECS_PER_HOUR, "hour", "hours")
+        } else if duration < self.weeks_from {
+            (SECS_PER_DAY, "day", "days")
+        } else {
+            (SECS_PER_WEEK, "week", "weeks")
+        };
+
+        let unit_nanos = u128::from(unit_secs) * 1_000_000_000;
+        let nanos = duration.as_nanos();
+        let mut count = (nanos + unit_nanos / 2) / unit_nanos;
+        // A short non-zero span reads "about 1 second", never "0 seconds".
+        if nanos > 0 {
+            count = count.max(1);
+        }
+        let approximate = count * unit_nanos != nanos;
+
+        if direction == Direction::Future {
+            out.write_str("in ")?;
+        }
+        if approximate {
+            out.write_str("about ")?;
+        }
+        write!(out, "{} {}", count, if count == 1 { singular } else { plural })?;
+        if direction == Direction::Past {
+            out.write_str(" ago")?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Humanizer {
+    fn default() -> Self {
+        Humanizer::new()
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DurationExt, SignedDurationExt};
+
+    #[test]
+    fn test_unit_selection() {
+        let humanizer = Humanizer::new();
+        assert_eq!(humanizer.format(Duration::ZERO), "just now");
+        assert_eq!(humanizer.format(10.seconds()), "10 seconds");
+        assert_eq!(humanizer.format(45.seconds()), "about 1 minute");
+        assert_eq!(humanizer.format(44.minutes()), "44 minutes");
+        assert_eq!(humanizer.format(45.minutes()), "about 1 hour");
+        assert_eq!(humanizer.format(22.hours()), "about 1 day");
+        assert_eq!(humanizer.format((3 * 24).hours()), "3 days");
+        assert_eq!(humanizer.format((7 * 24).hours()), "1 week");
+        assert_eq!(humanizer.format((100 * 7 * 24).hours()), "100 weeks");
+    }
+
+    #[test]
+    fn test_rounding_marks_approximation() {
+        let humanizer = Humanizer::new();
+        assert_eq!(humanizer.format(2.hours()), "2 hours");
+        assert_eq!(humanizer.format(2.hours() + 29.minutes()), "about 2 hours");
+        assert_eq!(humanizer.format(2.hours() + 30.minutes()), "about 3 hours");
+        assert_eq!(humanizer.format(12.5.seconds()), "about 13 seconds");
+    }
+
+    #[test]
+    fn test_directions() {
+        let humanizer = Humanizer::new();
+        assert_eq!(humanizer.format_future(90.minutes()), "in about 2 hours");
+        assert_eq!(humanizer.format_past(1.minutes()), "1 minute ago");
+        assert_eq!(humanizer.format_past(1.seconds()), "just now");
+        assert_eq!(humanizer.format_relative((-3).signed_hours()), "3 hours ago");
+        assert_eq!(humanizer.format_relative(3.signed_hours()), "in 3 hours");
+    }
+
+    #[test]
+    fn test_custom_thresholds() {
+        let humanizer = Humanizer::new()
+            .just_now_below(Duration::ZERO)
+            .minutes_from(2.minutes())
+            .days_from(48.hours())
+            .weeks_from(Duration::MAX);
+        assert_eq!(humanizer.format(1.seconds()), "1 second");
+        assert_eq!(humanizer.format(90.seconds()), "90 seconds");
+        assert_eq!(humanizer.format(30.hours()), "30 hours");
+        assert_eq!(humanizer.format((30 * 24).hours()), "30 days");
+    }
+
+    #[test]
+    fn test_zero_without_just_now() {
+        let humanizer = Humanizer::new().just_now_below(Duration::ZERO);
+        assert_eq!(humanizer.format(Duration::ZERO), "0 seconds");
+        assert_eq!(humanizer.format(1.nanoseconds()), "about 1 second");
+    }
+}
@@ -0,0 +1,215 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use core::fmt::Write;
+use core::time::Duration;
+
+use crate::grammar::{split_number, NANOS_PER_SEC};
+use crate::parse::{total_to_duration, ParseDurationError};
+
+/// Designators of the date part, in the order ISO 8601 requires them.
+///
+/// Years and months have no length (`None`) and are rejected when encountered.
+const DATE_DESIGNATORS: [(char, Option<u128>); 4] = [
+    ('Y', None),
+    ('M', None),
+    ('W', Some(604_800 * NANOS_PER_SEC)),
+    ('D', Some(86_400 * NANOS_PER_SEC)),
+];
+
+/// Designators of the time part (after `T`), in the order ISO 8601 requires them.
+const TIME_DESIGNATORS: [(char, Option<u128>); 3] = [
+    ('H', Some(3_600 * NANOS_PER_SEC)),
+    ('M', Some(60 * NANOS_PER_SEC)),
+    ('S', Some(NANOS_PER_SEC)),
+];
+
+/// An extension trait that formats a `Duration` as an ISO 8601 duration, e.g. `"PT2H30M"`.
+///
+/// The output only uses the time designators `H`, `M` and `S`, exactly like
+/// `java.time.Duration::toString()`, so a 26-hour span is written as `"PT26H"`
+/// rather than with a (calendar-dependent) day component.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, Iso8601Ext};
+///
+/// assert_eq!((2.hours() + 30.minutes()).to_iso8601(), "PT2H30M");
+/// assert_eq!(1500.milliseconds().to_iso8601(), "PT1.5S");
+/// ```
+#[cfg(feature = "alloc")]
+pub trait Iso8601Ext {
+    /// Formats this duration as an ISO 8601 duration string.
+    fn to_iso8601(&self) -> String;
+}
+
+#[cfg(feature = "alloc")]
+impl Iso8601Ext for Duration {
+    fn to_iso8601(&self) -> String {
+        let secs = self.as_secs();
+        let nanos = self.subsec_nanos();
+        if secs == 0 && nanos == 0 {
+            return String::from("PT0S");
+        }
+
+        let mut out = String::from("PT");
+        let (hours, minutes, seconds) = (secs / 3600, secs % 3600 / 60, secs % 60);
+        if hours > 0 {
+            write!(out, "{}H", hours).expect("writing to a String cannot fail");
+        }
+        if minutes > 0 {
+            write!(out, "{}M", minutes).expect("writing to a String cannot fail");
+        }
+        if seconds > 0 || nanos > 0 {
+            write!(out, "{}", seconds).expect("writing to a String cannot fail");
+            if nanos > 0 {
+                // Trim trailing zeros from the nine-digit fraction.
+                let mut fraction = nanos;
+                let mut width = 9;
+                while fraction.is_multiple_of(10) {
+                    fraction /= 10;
+                    width -= 1;
+                }
+                write!(out, ".{:0width$}", fraction, width = width).expect("writing to a String cannot fail");
+            }
+            out.push('S');
+        }
+        out
+    }
+}
+
+/// Parses an ISO 8601 duration string such as `"PT2H30M"` or `"P1DT2H30M"`.
+///
+/// Weeks (`W`) and days (`D`) are treated as **fixed spans** of 168 and 24 hours.
+/// Years (`Y`) and months (`M` before `T`) have no fixed length and are rejected
+/// with [`ParseDurationError::CalendarUnit`]. Designators are case-insensitive and
+/// any component may carry a `.` fraction.
+///
+/// # Errors
+///
+/// Returns a [`ParseDurationError`] if the input is empty, negative, not laid out as
+/// an ISO 8601 duration, uses years or months, or exceeds the capacity of `Duration`.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{from_iso8601, DurationExt, ParseDurationError};
+///
+/// assert_eq!(from_iso8601("PT2H30M"), Ok(2.hours() + 30.minutes()));
+/// assert_eq!(from_iso8601("P1DT2H"), Ok(26.hours()));
+/// assert_eq!(from_iso8601("P1M"), Err(ParseDurationError::CalendarUnit));
+/// ```
+pub fn from_iso8601(input: &str) -> Result<Duration, ParseDurationError> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err(ParseDurationError::Empty);
+    }
+    if input.starts_with('-') {
+        return Err(ParseDurationError::Negative);
+    }
+
+    let rest = input
+        .strip_prefix(['P', 'p'])
+        .ok_or(ParseDurationError::InvalidFormat)?;
+    let (date, time) = match rest.find(['T', 't']) {
+        Some(index) => (&rest[..index], Some(&rest[index + 1..])),
+        None => (rest, None),
+    };
+    if time == Some("") || (date.is_empty() && time.is_none()) {
+        return Err(ParseDurationError::InvalidFormat);
+    }
+
+    let date_nanos = sum_components(date, &DATE_DESIGNATORS)?;
+    let time_nanos = sum_components(time.unwrap_or(""), &TIME_DESIGNATORS)?;
+    let total = date_nanos
+        .checked_add(time_nanos)
+        .ok_or(ParseDurationError::Overflow)?;
+    total_to_duration(total)
+}
+
+/// Sums `<number><designator>` components, enforcing the designator order.
+fn sum_components(mut input: &str, designators: &[(char, Option<u128>)]) -> Result<u128, ParseDurationError> {
+    let mut total: u128 = 0;
+    let mut next = 0;
+    while !input.is_empty() {
+        let (number, rest) = split_number(input)?;
+        let designator = rest
+            .chars()
+            .next()
+            .ok_or(ParseDurationError::MissingUnit)?;
+
+        let index = designators
+            .iter()
+            .position(|(candidate, _)| candidate.eq_ignore_ascii_case(&designator))
+            .ok_or(ParseDurationError::UnknownUnit)?;
+        if index < next {
+            return Err(ParseDurationError::InvalidFormat);
+        }
+        let unit_nanos = designators[index].1.ok_or(ParseDurationError::CalendarUnit)?;
+        next = index + 1;
+
+        let nanos = number.to_nanos(unit_nanos).ok_or(ParseDurationError::Overflow)?;
+        total = total.checked_add(nanos).ok_or(ParseDurationError::Overflow)?;
+        input = &rest[designator.len_utf8()..];
+    }
+    Ok(total)
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_to_iso8601() {
+        assert_eq!((2.hours() + 30.minutes()).to_iso8601(), "PT2H30M");
+        assert_eq!(26.hours().to_iso8601(), "PT26H");
+        assert_eq!((1.hours() + 5.seconds()).to_iso8601(), "PT1H5S");
+        assert_eq!(Duration::ZERO.to_iso8601(), "PT0S");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_to_iso8601_fraction() {
+        assert_eq!(1500.milliseconds().to_iso8601(), "PT1.5S");
+        assert_eq!(7.nanoseconds().to_iso8601(), "PT0.000000007S");
+    }
+
+    #[test]
+    fn test_from_iso8601() {
+        assert_eq!(from_iso8601("PT2H30M"), Ok(2.hours() + 30.minutes()));
+        assert_eq!(from_iso8601("P1DT2H30M"), Ok(26.hours() + 30.minutes()));
+        assert_eq!(from_iso8601("P2W"), Ok((14 * 24).hours()));
+        assert_eq!(from_iso8601("pt1.5s"), Ok(1500.milliseconds()));
+        assert_eq!(from_iso8601("PT0S"), Ok(Duration::ZERO));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_iso8601_round_trip() {
+        let value = 49.hours() + 7.minutes() + 3.seconds() + 120.microseconds();
+        assert_eq!(from_iso8601(&value.to_iso8601()), Ok(value));
+    }
+
+    #[test]
+    fn test_from_iso8601_rejects_calendar_units() {
+        assert_eq!(from_iso8601("P1Y"), Err(ParseDurationError::CalendarUnit));
+        assert_eq!(from_iso8601("P3M"), Err(ParseDurationError::CalendarUnit));
+        assert_eq!(from_iso8601("PT3M"), Ok(3.minutes()));
+    }
+
+    #[test]
+    fn test_from_iso8601_errors() {
+        assert_eq!(from_iso8601(""), Err(ParseDurationError::Empty));
+        assert_eq!(from_iso8601("-PT5S"), Err(ParseDurationError::Negative));
+        assert_eq!(from_iso8601("2H"), Err(ParseDurationError::InvalidFormat));
+        assert_eq!(from_iso8601("P"), Err(ParseDurationError::InvalidFormat));
+        assert_eq!(from_iso8601("PT"), Err(ParseDurationError::InvalidFormat));
+        assert_eq!(from_iso8601("PT5M2H"), Err(ParseDurationError::InvalidFormat));
+        assert_eq!(from_iso8601("PT5"), Err(ParseDurationError::MissingUnit));
+        assert_eq!(from_iso8601("PT5X"), Err(ParseDurationError::UnknownUnit));
+    }
+}
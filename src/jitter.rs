@@ -0,0 +1,108 @@
+use std::time::Duration;
+
+use crate::random;
+
+/// An extension trait that randomizes a `Duration`, e.g. to decorrelate retry storms.
+///
+/// Randomness comes from a small thread-local generator seeded per process, so this
+/// needs the `rand` feature (which implies `std`) but no external crate. It is not
+/// cryptographically secure.
+/// Results saturate at `Duration::MAX` instead of overflowing.
+///
+/// # Panics
+///
+/// [`jitter`](Self::jitter) panics if the fraction is outside `0.0..=1.0`, and
+/// [`jitter_between`](Self::jitter_between) panics if either factor is negative or not
+/// finite, or if `min > max`.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, JitterExt};
+///
+/// let delay = 10.seconds().jitter(0.2);
+/// assert!(delay >= 8.seconds() && delay <= 12.seconds());
+///
+/// let delay = 10.seconds().jitter_between(0.5, 1.0);
+/// assert!(delay >= 5.seconds() && delay <= 10.seconds());
+/// ```
+pub trait JitterExt {
+    /// Returns a random duration within `fraction` of this one in either direction.
+    ///
+    /// `10.seconds().jitter(0.2)` lies between 8 and 12 seconds.
+    fn jitter(self, fraction: f64) -> Duration;
+    /// Returns this duration scaled by a random factor between `min` and `max`.
+    ///
+    /// `delay.jitter_between(0.0, 1.0)` is the "full jitter" of a backoff delay.
+    fn jitter_between(self, min: f64, max: f64) -> Duration;
+}
+
+impl JitterExt for Duration {
+    #[track_caller]
+    fn jitter(self, fraction: f64) -> Duration {
+        assert!(
+            (0.0..=1.0).contains(&fraction),
+            "jitter fraction must be between 0.0 and 1.0: got {}",
+            fraction
+        );
+        self.jitter_between(1.0 - fraction, 1.0 + fraction)
+    }
+
+    #[track_caller]
+    fn jitter_between(self, min: f64, max: f64) -> Duration {
+        assert!(
+            min.is_finite() && max.is_finite() && 0.0 <= min && min <= max,
+            "jitter factors must be finite, non-negative and ordered: got {} and {}",
+            min,
+            max
+        );
+        let factor = min + (max - min) * random::next_f64();
+        Duration::try_from_secs_f64(self.as_secs_f64() * factor).unwrap_or(Duration::MAX)
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_jitter_within_bounds() {
+        for _ in 0..1_000 {
+            let delay = 10.seconds().jitter(0.2);
+            assert!(delay >= 8.seconds() && delay <= 12.seconds(), "{:?}", delay);
+        }
+    }
+
+    #[test]
+    fn test_jitter_between_within_bounds() {
+        for _ in 0..1_000 {
+            let delay = 1.seconds().jitter_between(0.0, 0.5);
+            assert!(delay <= 500.milliseconds(), "{:?}", delay);
+        }
+    }
+
+    #[test]
+    fn test_jitter_zero_fraction_is_identity() {
+        assert_eq!(3.seconds().jitter(0.0), 3.seconds());
+        assert_eq!(Duration::ZERO.jitter(1.0), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_jitter_saturates() {
+        assert_eq!(Duration::MAX.jitter_between(2.0, 3.0), Duration::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "jitter fraction must be between 0.0 and 1.0")]
+    fn test_jitter_fraction_out_of_range_panics() {
+        let _ = 1.seconds().jitter(1.5);
+    }
+
+    #[test]
+    #[should_panic(expected = "jitter factors must be finite, non-negative and ordered")]
+    fn test_jitter_between_unordered_panics() {
+        let _ = 1.seconds().jitter_between(1.0, 0.5);
+    }
+}
@@ -1,16 +1,180 @@
-use std::time::Duration;
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::time::Duration;
+
+mod access;
+mod aggregate;
+mod approx;
+mod backoff;
+#[cfg(feature = "std")]
+mod budget;
+mod canonical;
+mod compare;
+mod compose;
+pub mod consts;
+#[cfg(feature = "std")]
+mod deadline;
+mod display;
+mod ffi;
+#[cfg(feature = "alloc")]
+mod format;
+mod frequency;
+mod grammar;
+#[cfg(feature = "alloc")]
+mod humanize;
+mod iso8601;
+#[cfg(feature = "rand")]
+mod jitter;
+mod locale;
+mod math;
+#[cfg(feature = "metrics")]
+mod metrics;
+mod nonzero;
+mod overflow;
+mod parse;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "std")]
+mod rate;
+mod range;
+#[cfg(feature = "std")]
+mod retry;
+mod round;
+mod signed;
+#[cfg(feature = "std")]
+mod sleep;
+#[cfg(feature = "std")]
+mod stopwatch;
+#[cfg(feature = "std")]
+mod ticker;
+mod typed;
+mod value;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+pub use access::{DurationAccessExt, DurationParts};
+pub use aggregate::DurationIteratorExt;
+pub use approx::{CalendarApprox, CalendarApproxExt, FixedLengthExt};
+pub use backoff::Backoff;
+#[cfg(feature = "std")]
+pub use budget::{Budget, BudgetExt};
+#[cfg(feature = "alloc")]
+pub use canonical::CanonicalExt;
+pub use canonical::from_canonical_str;
+pub use compare::DurationCompareExt;
+pub use compose::{ClockPairExt, DurationComposeExt};
+#[cfg(feature = "std")]
+pub use deadline::{DeadlineExt, InstantExt, SystemTimeExt, TimePoint};
+pub use display::{Clock, Compact, DurationDisplayExt, Verbose};
+pub use ffi::{from_ticks, DurationFfiExt};
+#[cfg(feature = "alloc")]
+pub use format::DurationFormatExt;
+pub use frequency::RateExt;
+#[cfg(feature = "alloc")]
+pub use humanize::Humanizer;
+#[cfg(feature = "alloc")]
+pub use iso8601::Iso8601Ext;
+pub use iso8601::from_iso8601;
+#[cfg(feature = "rand")]
+pub use jitter::JitterExt;
+pub use locale::{English, Locale, UnitNames};
+pub use math::DurationMathExt;
+#[cfg(feature = "metrics")]
+pub use metrics::{Buckets, DurationBucketExt};
+pub use nonzero::{NonZeroDuration, NonZeroDurationExt, ZeroDurationError};
+pub use overflow::{DurationPolicyExt, Overflow};
+pub use parse::{parse_duration, FromHumanStr, HumanDuration, ParseDurationError};
+#[cfg(feature = "rand")]
+pub use random::{random_between, UniformDuration};
+pub use range::{DurationRange, DurationStepExt};
+#[cfg(feature = "std")]
+pub use rate::{Debounce, Throttle};
+#[cfg(feature = "std")]
+pub use retry::RetryPolicy;
+pub use round::{DurationRoundExt, Unit};
+pub use signed::{NegativeDurationError, SignedDuration, SignedDurationExt};
+#[cfg(feature = "std")]
+pub use sleep::DurationSleepExt;
+#[cfg(feature = "std")]
+pub use stopwatch::Stopwatch;
+#[cfg(feature = "std")]
+pub use ticker::{TickPolicy, Ticker, TickerExt, TickerHandle};
+pub use typed::{Hours, Micros, Millis, Minutes, Nanos, Seconds, TypedDurationExt};
+pub use value::{IntoDurationValue, ValueError};
+#[cfg(feature = "wasm")]
+pub use wasm::{from_js_millis, DurationJsExt};
+
+/// Parses a duration string at compile time, e.g. `duration!("1h 30m 500ms")`.
+///
+/// Requires the `macros` feature. The string uses the syntax of [`parse_duration`];
+/// invalid strings fail the build, and the expansion is a plain `Duration::new(..)`
+/// call with no runtime parsing, so it can initialize `const` items.
+///
+/// ```rust
+/// use duration_extender::{duration, DurationExt};
+/// use std::time::Duration;
+///
+/// const REFRESH: Duration = duration!("1h 30m 500ms");
+/// assert_eq!(REFRESH, 90.minutes() + 500.milliseconds());
+/// ```
+///
+/// ```compile_fail
+/// // error: unknown unit in duration string
+/// let _ = duration_extender::duration!("30x");
+/// ```
+#[cfg(feature = "macros")]
+pub use duration_extender_macros::duration;
+
+use value::{NANOS_PER_HOUR, NANOS_PER_MICRO, NANOS_PER_MILLI, NANOS_PER_MINUTE, NANOS_PER_SEC};
 
-/// An extension trait that adds fluent time unit methods to integer primitives,
+/// An extension trait that adds fluent time unit methods to numeric primitives,
 /// allowing for highly readable time duration creation.
 ///
+/// It is implemented for every integer primitive (`u8` through `u128`, `i8` through
+/// `i128`, `usize` and `isize`) as well as `f32` and `f64`.
+///
 /// This crate is optimized for **system timing** (timeouts, sleeps, fixed cache TTLs).
 /// It explicitly excludes methods for units longer than hours (days, weeks) 
 /// to prevent calendar errors related to Daylight Saving Time (DST) and time zones.
+/// Coarse, explicitly approximated months and years are available separately
+/// through [`CalendarApproxExt`].
 ///
 /// # Panics
 ///
-/// - Signed integers (`i32`, `i64`) **panic** if the value is negative.
-/// - Overflow panics for `.minutes()` and `.hours()` when the resulting seconds exceed `u64::MAX`.
+/// - Signed integers **panic** if the value is negative. These methods always return
+///   the unsigned `Duration`; use [`SignedDurationExt`] (`(-30).signed_seconds()`) for
+///   negative spans.
+/// - Overflow panics when the resulting seconds exceed `u64::MAX`. For 64-bit and
+///   narrower integers this can only happen with `.minutes()` and `.hours()`;
+///   `u128` and `i128` are range checked for every unit.
+/// - Floats **panic** on NaN and infinity.
+///
+/// Every method has a `checked_*` counterpart that returns `None` instead of panicking,
+/// mirroring the naming of `Duration::checked_add`. As with the panicking methods,
+/// there are no day or week variants.
+///
+/// # Round trips through integer storage
+///
+/// `Duration::as_nanos()`, `as_micros()` and `as_millis()` return `u128`, and `u128`
+/// (like `i128`) implements this trait with full range checking. Feeding a total back
+/// into the matching method restores the `Duration`, exactly for nanoseconds and
+/// truncated to the unit otherwise; use the `checked_*` variant for totals read from
+/// untrusted storage:
+///
+/// ```rust
+/// use duration_extender::DurationExt;
+/// use std::time::Duration;
+///
+/// let stored: u128 = Duration::MAX.as_nanos();
+/// assert_eq!(stored.nanoseconds(), Duration::MAX);
+/// assert_eq!((stored + 1).checked_nanoseconds(), None);
+///
+/// let millis: i128 = 1_500;
+/// assert_eq!(millis.checked_milliseconds(), Some(Duration::from_millis(1_500)));
+/// ```
 ///
 /// # Examples
 ///
@@ -29,6 +193,11 @@ use std::time::Duration;
 /// // Signed integers must be non-negative
 /// let elapsed = 100.seconds(); // ✅ Works
 /// // let bad = (-100).seconds(); // ❌ Panics!
+///
+/// // Checked variants return `None` instead of panicking
+/// const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+/// let configured: i64 = -1;
+/// assert_eq!(configured.checked_seconds().unwrap_or(DEFAULT_TIMEOUT), DEFAULT_TIMEOUT);
 /// ```
 pub trait DurationExt {
     /// Creates a `Duration` representing this many seconds.
@@ -43,186 +212,82 @@ pub trait DurationExt {
     fn microseconds(self) -> Duration;
     /// Creates a `Duration` representing this many nanoseconds.
     fn nanoseconds(self) -> Duration;
-}
-
-// ===== Implementation for unsigned integers =====
-impl DurationExt for u64 {
-    fn seconds(self) -> Duration {
-        Duration::from_secs(self)
-    }
-
-    fn minutes(self) -> Duration {
-        let secs = self.checked_mul(60)
-            .expect(&format!("duration value {} minutes overflows u64 seconds capacity", self));
-        Duration::from_secs(secs)
-    }
 
-    fn hours(self) -> Duration {
-        let secs = self.checked_mul(3600)
-            .expect(&format!("duration value {} hours overflows u64 seconds capacity", self));
-        Duration::from_secs(secs)
-    }
-
-    fn milliseconds(self) -> Duration {
-        Duration::from_millis(self)
-    }
-
-    fn microseconds(self) -> Duration {
-        Duration::from_micros(self)
-    }
-
-    fn nanoseconds(self) -> Duration {
-        Duration::from_nanos(self)
-    }
+    /// Creates a `Duration` representing this many seconds, or `None` if the value is
+    /// negative, not finite, or overflows.
+    fn checked_seconds(self) -> Option<Duration>;
+    /// Creates a `Duration` representing this many minutes, or `None` if the value is
+    /// negative, not finite, or overflows.
+    fn checked_minutes(self) -> Option<Duration>;
+    /// Creates a `Duration` representing this many hours, or `None` if the value is
+    /// negative, not finite, or overflows.
+    fn checked_hours(self) -> Option<Duration>;
+    /// Creates a `Duration` representing this many milliseconds, or `None` if the value is
+    /// negative, not finite, or overflows.
+    fn checked_milliseconds(self) -> Option<Duration>;
+    /// Creates a `Duration` representing this many microseconds, or `None` if the value is
+    /// negative, not finite, or overflows.
+    fn checked_microseconds(self) -> Option<Duration>;
+    /// Creates a `Duration` representing this many nanoseconds, or `None` if the value is
+    /// negative, not finite, or overflows.
+    fn checked_nanoseconds(self) -> Option<Duration>;
 }
 
-impl DurationExt for u32 {
+// ===== Implementation for every numeric primitive =====
+// The conversions and their negative/overflow checks live in `value`.
+impl<T: IntoDurationValue> DurationExt for T {
+    #[track_caller]
     fn seconds(self) -> Duration {
-        Duration::from_secs(self as u64)
+        value::to_duration(self, NANOS_PER_SEC, "seconds")
     }
 
-    // Delegates to u64's implementation which includes the correct overflow check
+    #[track_caller]
     fn minutes(self) -> Duration {
-        (self as u64).minutes()
+        value::to_duration(self, NANOS_PER_MINUTE, "minutes")
     }
 
-    // Delegates to u64's implementation which includes the correct overflow check
+    #[track_caller]
     fn hours(self) -> Duration {
-        (self as u64).hours()
+        value::to_duration(self, NANOS_PER_HOUR, "hours")
     }
 
+    #[track_caller]
     fn milliseconds(self) -> Duration {
-        Duration::from_millis(self as u64)
+        value::to_duration(self, NANOS_PER_MILLI, "milliseconds")
     }
 
+    #[track_caller]
     fn microseconds(self) -> Duration {
-        Duration::from_micros(self as u64)
+        value::to_duration(self, NANOS_PER_MICRO, "microseconds")
     }
 
+    #[track_caller]
     fn nanoseconds(self) -> Duration {
-        Duration::from_nanos(self as u64)
+        value::to_duration(self, 1, "nanoseconds")
     }
-}
 
-// ===== Implementation for signed integers =====
-impl DurationExt for i64 {
-    fn seconds(self) -> Duration {
-        assert!(self >= 0, "duration cannot be negative: got {} seconds", self);
-        Duration::from_secs(self as u64)
+    fn checked_seconds(self) -> Option<Duration> {
+        self.checked_units(NANOS_PER_SEC).ok()
     }
 
-    fn minutes(self) -> Duration {
-        assert!(self >= 0, "duration cannot be negative: got {} minutes", self);
-        // Delegates to u64 for the multiplication and overflow check
-        (self as u64).minutes()
+    fn checked_minutes(self) -> Option<Duration> {
+        self.checked_units(NANOS_PER_MINUTE).ok()
     }
 
-    fn hours(self) -> Duration {
-        assert!(self >= 0, "duration cannot be negative: got {} hours", self);
-        // Delegates to u64 for the multiplication and overflow check
-        (self as u64).hours()
+    fn checked_hours(self) -> Option<Duration> {
+        self.checked_units(NANOS_PER_HOUR).ok()
     }
 
-    fn milliseconds(self) -> Duration {
-        assert!(self >= 0, "duration cannot be negative: got {} milliseconds", self);
-        Duration::from_millis(self as u64)
+    fn checked_milliseconds(self) -> Option<Duration> {
+        self.checked_units(NANOS_PER_MILLI).ok()
     }
 
-    fn microseconds(self) -> Duration {
-        assert!(self >= 0, "duration cannot be negative: got {} microseconds", self);
-        Duration::from_micros(self as u64)
+    fn checked_microseconds(self) -> Option<Duration> {
+        self.checked_units(NANOS_PER_MICRO).ok()
     }
 
-    fn nanoseconds(self) -> Duration {
-        assert!(self >= 0, "duration cannot be negative: got {} nanoseconds", self);
-        Duration::from_nanos(self as u64)
-    }
-}
-
-impl DurationExt for i32 {
-    fn seconds(self) -> Duration {
-        assert!(self >= 0, "duration cannot be negative: got {} seconds", self);
-        Duration::from_secs(self as u64)
-    }
-
-    fn minutes(self) -> Duration {
-        assert!(self >= 0, "duration cannot be negative: got {} minutes", self);
-        // Delegates to u64 for the multiplication and overflow check
-        (self as u64).minutes()
-    }
-
-    fn hours(self) -> Duration {
-        assert!(self >= 0, "duration cannot be negative: got {} hours", self);
-        // Delegates to u64 for the multiplication and overflow check
-        (self as u64).hours()
-    }
-
-    fn milliseconds(self) -> Duration {
-        assert!(self >= 0, "duration cannot be negative: got {} milliseconds", self);
-        Duration::from_millis(self as u64)
-    }
-
-    fn microseconds(self) -> Duration {
-        assert!(self >= 0, "duration cannot be negative: got {} microseconds", self);
-        Duration::from_micros(self as u64)
-    }
-
-    fn nanoseconds(self) -> Duration {
-        assert!(self >= 0, "duration cannot be negative: got {} nanoseconds", self);
-        Duration::from_nanos(self as u64)
-    }
-}
-
-impl DurationExt for f64 {
-    fn seconds(self) -> Duration {
-        Duration::from_secs_f64(self)
-    }
-
-    fn minutes(self) -> Duration {
-        Duration::from_secs_f64(self * 60.0)
-    }
-
-    fn hours(self) -> Duration {
-        Duration::from_secs_f64(self * 3600.0)
-    }
-
-    fn milliseconds(self) -> Duration {
-        Duration::from_secs_f64(self / 1000.0)
-    }
-
-    fn microseconds(self) -> Duration {
-        Duration::from_secs_f64(self / 1_000_000.0)
-    }
-
-    fn nanoseconds(self) -> Duration {
-        Duration::from_secs_f64(self / 1_000_000_000.0)
-    }
-}
-
-
-impl DurationExt for f32 {
-    fn seconds(self) -> Duration {
-        Duration::from_secs_f32(self)
-    }
-
-    fn minutes(self) -> Duration {
-        Duration::from_secs_f32(self * 60.0)
-    }
-
-    fn hours(self) -> Duration {
-        Duration::from_secs_f32(self * 3600.0)
-    }
-
-    fn milliseconds(self) -> Duration {
-        Duration::from_secs_f32(self / 1000.0)
-    }
-
-    fn microseconds(self) -> Duration {
-        Duration::from_secs_f32(self / 1_000_000.0)
-    }
-
-    fn nanoseconds(self) -> Duration {
-        Duration::from_secs_f32(self / 1_000_000_000.0)
+    fn checked_nanoseconds(self) -> Option<Duration> {
+        self.checked_units(1).ok()
     }
 }
 
@@ -318,6 +383,104 @@ mod tests {
         assert_eq!(val.hours(), Duration::from_secs(18000));
     }
 
+    // --- Narrow and pointer-sized integer Tests ---
+    #[test]
+    fn test_narrow_and_pointer_sized_integers() {
+        assert_eq!(5u8.minutes(), Duration::from_secs(300));
+        assert_eq!(5u16.hours(), Duration::from_secs(18000));
+        assert_eq!(5usize.seconds(), Duration::from_secs(5));
+        assert_eq!(5i8.milliseconds(), Duration::from_millis(5));
+        assert_eq!(5i16.microseconds(), Duration::from_micros(5));
+        assert_eq!(5isize.nanoseconds(), Duration::from_nanos(5));
+    }
+
+    #[test]
+    #[should_panic(expected = "duration cannot be negative: got -1 seconds")]
+    fn test_isize_negative_panics() {
+        let _ = (-1isize).seconds();
+    }
+
+    // --- u128/i128 Tests ---
+    #[test]
+    fn test_u128_within_capacity() {
+        assert_eq!(5u128.minutes(), Duration::from_secs(300));
+        assert_eq!((u64::MAX as u128).seconds(), Duration::from_secs(u64::MAX));
+        assert_eq!(Duration::MAX.as_nanos().nanoseconds(), Duration::MAX);
+        assert_eq!(Duration::MAX.as_millis().milliseconds(), Duration::new(u64::MAX, 999_000_000));
+    }
+
+    #[test]
+    fn test_u128_totals_round_trip() {
+        let samples = [
+            Duration::ZERO,
+            Duration::new(1, 1),
+            Duration::from_nanos(u64::MAX) + Duration::from_nanos(1),
+            Duration::new(u64::MAX / 3, 123_456_789),
+            Duration::MAX,
+        ];
+        for duration in samples {
+            assert_eq!(duration.as_nanos().checked_nanoseconds(), Some(duration));
+            assert_eq!((duration.as_nanos() as i128).checked_nanoseconds(), Some(duration));
+            let micros = Duration::new(duration.as_secs(), duration.subsec_micros() * 1_000);
+            assert_eq!(duration.as_micros().checked_microseconds(), Some(micros));
+            let millis = Duration::new(duration.as_secs(), duration.subsec_millis() * 1_000_000);
+            assert_eq!(duration.as_millis().checked_milliseconds(), Some(millis));
+        }
+        assert_eq!((Duration::MAX.as_micros() + 1).checked_microseconds(), None);
+        assert_eq!((Duration::MAX.as_millis() + 1).checked_milliseconds(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows u64 seconds capacity")]
+    fn test_u128_seconds_panics_on_overflow() {
+        let _ = (u64::MAX as u128 + 1).seconds();
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows u64 seconds capacity")]
+    fn test_u128_nanoseconds_panics_on_overflow() {
+        let _ = (Duration::MAX.as_nanos() + 1).nanoseconds();
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows u64 seconds capacity")]
+    fn test_u128_hours_panics_on_overflow() {
+        let _ = u128::MAX.hours();
+    }
+
+    #[test]
+    #[should_panic(expected = "duration cannot be negative")]
+    fn test_i128_negative_panics() {
+        let _ = (-1i128).milliseconds();
+    }
+
+    // --- Checked variant Tests ---
+    #[test]
+    fn test_checked_within_range() {
+        assert_eq!(5u64.checked_minutes(), Some(Duration::from_secs(300)));
+        assert_eq!(2i32.checked_hours(), Some(Duration::from_secs(7200)));
+        assert_eq!(1500u16.checked_milliseconds(), Some(Duration::from_millis(1500)));
+        assert_eq!(3u8.checked_microseconds(), Some(Duration::from_micros(3)));
+        assert_eq!(3u8.checked_nanoseconds(), Some(Duration::from_nanos(3)));
+        assert_eq!(0.5.checked_seconds(), Some(Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn test_checked_overflow_is_none() {
+        assert_eq!(OVERFLOW_MINUTES.checked_minutes(), None);
+        assert_eq!(OVERFLOW_HOURS.checked_hours(), None);
+        assert_eq!(MAX_FOR_HOURS.checked_hours(), Some(Duration::from_secs(MAX_FOR_HOURS * 3600)));
+        assert_eq!(u128::MAX.checked_nanoseconds(), None);
+    }
+
+    #[test]
+    fn test_checked_negative_and_nan_are_none() {
+        assert_eq!((-5i64).checked_seconds(), None);
+        assert_eq!((-1i8).checked_hours(), None);
+        assert_eq!(f64::NAN.checked_seconds(), None);
+        assert_eq!(f32::INFINITY.checked_minutes(), None);
+    }
+
     #[test]
     fn test_f64_fractional() {
         let half = 0.5.seconds();
@@ -0,0 +1,140 @@
+use crate::Unit;
+
+/// The unit names used by the localized formatters, such as
+/// [`DurationFormatExt::to_human_string_with`](crate::DurationFormatExt::to_human_string_with).
+///
+/// [`English`] is built in, and [`UnitNames`] covers languages whose plurals only
+/// distinguish one from many. Implement this trait directly for other plural rules.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{Locale, Unit};
+///
+/// struct Polish;
+///
+/// impl Locale for Polish {
+///     fn unit_name(&self, unit: Unit, count: u64) -> &str {
+///         let few = (2..=4).contains(&(count % 10)) && !(12..=14).contains(&(count % 100));
+///         match (unit, count, few) {
+///             (Unit::Hours, 1, _) => "godzina",
+///             (Unit::Hours, _, true) => "godziny",
+///             (Unit::Hours, _, false) => "godzin",
+///             (_, _, _) => "?",
+///         }
+///     }
+/// }
+///
+/// assert_eq!(Polish.unit_name(Unit::Hours, 3), "godziny");
+/// assert_eq!(Polish.unit_name(Unit::Hours, 5), "godzin");
+/// assert_eq!(Polish.unit_name(Unit::Hours, 12), "godzin");
+/// ```
+pub trait Locale {
+    /// Returns the name of `unit` to print after `count`, e.g. `"hour"` or `"hours"`.
+    fn unit_name(&self, unit: Unit, count: u64) -> &str;
+
+    /// Returns the text printed between two components. Defaults to a single space.
+    fn separator(&self) -> &str {
+        " "
+    }
+}
+
+impl<L: Locale + ?Sized> Locale for &L {
+    fn unit_name(&self, unit: Unit, count: u64) -> &str {
+        (**self).unit_name(unit, count)
+    }
+
+    fn separator(&self) -> &str {
+        (**self).separator()
+    }
+}
+
+/// The built-in English unit names: `"2 hours 30 minutes"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct English;
+
+/// English names, ordered like [`Unit`].
+const ENGLISH: UnitNames = UnitNames::new(
+    ["nanosecond", "microsecond", "millisecond", "second", "minute", "hour", "day", "week"],
+    ["nanoseconds", "microseconds", "milliseconds", "seconds", "minutes", "hours", "days", "weeks"],
+);
+
+impl Locale for English {
+    fn unit_name(&self, unit: Unit, count: u64) -> &str {
+        ENGLISH.unit_name(unit, count)
+    }
+}
+
+/// A data-driven [`Locale`] with a singular and a plural name per unit.
+///
+/// The singular is used for a count of exactly one, the plural for everything else.
+/// Both arrays are ordered like [`Unit`], from nanoseconds to weeks.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{Locale, Unit, UnitNames};
+///
+/// const GERMAN: UnitNames = UnitNames::new(
+///     ["Nanosekunde", "Mikrosekunde", "Millisekunde", "Sekunde", "Minute", "Stunde", "Tag", "Woche"],
+///     ["Nanosekunden", "Mikrosekunden", "Millisekunden", "Sekunden", "Minuten", "Stunden", "Tage", "Wochen"],
+/// );
+///
+/// assert_eq!(GERMAN.unit_name(Unit::Hours, 1), "Stunde");
+/// assert_eq!(GERMAN.unit_name(Unit::Hours, 2), "Stunden");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnitNames {
+    singular: [&'static str; 8],
+    plural: [&'static str; 8],
+    separator: &'static str,
+}
+
+impl UnitNames {
+    /// Creates a locale from singular and plural unit names, ordered like [`Unit`].
+    pub const fn new(singular: [&'static str; 8], plural: [&'static str; 8]) -> Self {
+        UnitNames { singular, plural, separator: " " }
+    }
+
+    /// Sets the text printed between two components, e.g. `", "`.
+    pub const fn with_separator(mut self, separator: &'static str) -> Self {
+        self.separator = separator;
+        self
+    }
+}
+
+impl Locale for UnitNames {
+    fn unit_name(&self, unit: Unit, count: u64) -> &str {
+        if count == 1 {
+            self.singular[unit as usize]
+        } else {
+            self.plural[unit as usize]
+        }
+    }
+
+    fn separator(&self) -> &str {
+        self.separator
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_english_names() {
+        assert_eq!(English.unit_name(Unit::Hours, 1), "hour");
+        assert_eq!(English.unit_name(Unit::Hours, 2), "hours");
+        assert_eq!(English.unit_name(Unit::Nanos, 0), "nanoseconds");
+        assert_eq!(English.unit_name(Unit::Weeks, 1), "week");
+        assert_eq!(English.separator(), " ");
+    }
+
+    #[test]
+    fn test_unit_names_separator() {
+        let names = ENGLISH.with_separator(", ");
+        assert_eq!(names.separator(), ", ");
+        assert_eq!(names.unit_name(Unit::Millis, 1), "millisecond");
+    }
+}
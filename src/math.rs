@@ -0,0 +1,216 @@
+use core::time::Duration;
+
+use crate::value::duration_from_nanos;
+
+/// An extension trait for scaling and dividing durations without std's panicking edge cases.
+///
+/// Timeouts are often derived from a budget ("75% of the remaining time"). This trait
+/// offers those computations with explicit overflow behaviour: the plain methods panic
+/// like the constructors of this crate, `checked_*` variants return `None`, and
+/// `saturating_*` variants clamp to `Duration::ZERO` or `Duration::MAX`.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, DurationMathExt};
+///
+/// let budget = 2.seconds();
+/// assert_eq!(budget.percent(75), 1500.milliseconds());
+/// assert_eq!(budget.halved(), 1.seconds());
+/// assert_eq!(budget.mul_f64_checked(-1.0), None);
+///
+/// assert_eq!(10.seconds().div_duration(4.seconds()), 2.5);
+/// assert_eq!(10.seconds().div_rem_duration(4.seconds()), (2, 2.seconds()));
+/// ```
+pub trait DurationMathExt {
+    /// Multiplies this duration by `factor`, or returns `None` if `factor` is negative,
+    /// not finite, or the result overflows.
+    fn mul_f64_checked(self, factor: f64) -> Option<Duration>;
+    /// Multiplies this duration by `factor`, clamping to `Duration::MAX` on overflow and
+    /// to `Duration::ZERO` for negative or NaN factors.
+    ///
+    /// A zero duration stays zero for every factor, including infinity.
+    fn mul_f64_saturating(self, factor: f64) -> Duration;
+
+    /// Returns how many times `other` fits into this duration, as a float.
+    ///
+    /// Like `f64` division, dividing by `Duration::ZERO` gives infinity (or NaN for zero
+    /// by zero).
+    fn div_duration(self, other: Duration) -> f64;
+    /// Returns how many whole times `other` fits into this duration, and what is left over.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other` is zero.
+    fn div_rem_duration(self, other: Duration) -> (u128, Duration);
+    /// Like [`div_rem_duration`](Self::div_rem_duration), but returns `None` if `other` is zero.
+    fn checked_div_rem_duration(self, other: Duration) -> Option<(u128, Duration)>;
+
+    /// Returns `percent` percent of this duration, truncated to whole nanoseconds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows, which needs `percent` above 100.
+    fn percent(self, percent: u32) -> Duration;
+    /// Like [`percent`](Self::percent), but returns `None` on overflow.
+    fn checked_percent(self, percent: u32) -> Option<Duration>;
+    /// Like [`percent`](Self::percent), but clamps to `Duration::MAX` on overflow.
+    fn saturating_percent(self, percent: u32) -> Duration;
+
+    /// Returns half of this duration, truncated to whole nanoseconds.
+    fn halved(self) -> Duration;
+    /// Returns twice this duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result overflows.
+    fn doubled(self) -> Duration;
+    /// Like [`doubled`](Self::doubled), but returns `None` on overflow.
+    fn checked_doubled(self) -> Option<Duration>;
+    /// Like [`doubled`](Self::doubled), but clamps to `Duration::MAX` on overflow.
+    fn saturating_doubled(self) -> Duration;
+}
+
+impl DurationMathExt for Duration {
+    fn mul_f64_checked(self, factor: f64) -> Option<Duration> {
+        if factor < 0.0 || !factor.is_finite() {
+            return None;
+        }
+        Duration::try_from_secs_f64(self.as_secs_f64() * factor).ok()
+    }
+
+    fn mul_f64_saturating(self, factor: f64) -> Duration {
+        let secs = self.as_secs_f64() * factor;
+        // Zero times infinity is NaN, so check the product rather than only the factor.
+        if self.is_zero() || secs.is_nan() || factor <= 0.0 {
+            return Duration::ZERO;
+        }
+        Duration::try_from_secs_f64(secs).unwrap_or(Duration::MAX)
+    }
+
+    fn div_duration(self, other: Duration) -> f64 {
+        self.as_secs_f64() / other.as_secs_f64()
+    }
+
+    #[track_caller]
+    fn div_rem_duration(self, other: Duration) -> (u128, Duration) {
+        match self.checked_div_rem_duration(other) {
+            Some(quotient_and_rest) => quotient_and_rest,
+            None => panic!("cannot divide {:?} by a zero duration", self),
+        }
+    }
+
+    fn checked_div_rem_duration(self, other: Duration) -> Option<(u128, Duration)> {
+        let divisor = other.as_nanos();
+        if divisor == 0 {
+            return None;
+        }
+        let nanos = self.as_nanos();
+        // The remainder is below `other`, so it always fits.
+        Some((nanos / divisor, duration_from_nanos(nanos % divisor)?))
+    }
+
+    #[track_caller]
+    fn percent(self, percent: u32) -> Duration {
+        match self.checked_percent(percent) {
+            Some(share) => share,
+            None => panic!("{}% of {:?} overflows u64 seconds capacity", percent, self),
+        }
+    }
+
+    fn checked_percent(self, percent: u32) -> Option<Duration> {
+        // At most ~1.8e28 * 4.3e9, well within `u128`.
+        duration_from_nanos(self.as_nanos() * u128::from(percent) / 100)
+    }
+
+    fn saturating_percent(self, percent: u32) -> Duration {
+        self.checked_percent(percent).unwrap_or(Duration::MAX)
+    }
+
+    fn halved(self) -> Duration {
+        self / 2
+    }
+
+    #[track_caller]
+    fn doubled(self) -> Duration {
+        match self.checked_doubled() {
+            Some(doubled) => doubled,
+            None => panic!("doubling {:?} overflows u64 seconds capacity", self),
+        }
+    }
+
+    fn checked_doubled(self) -> Option<Duration> {
+        self.checked_mul(2)
+    }
+
+    fn saturating_doubled(self) -> Duration {
+        self.saturating_mul(2)
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_mul_f64() {
+        assert_eq!(2.seconds().mul_f64_checked(0.75), Some(1500.milliseconds()));
+        assert_eq!(2.seconds().mul_f64_checked(-0.5), None);
+        assert_eq!(2.seconds().mul_f64_checked(f64::NAN), None);
+        assert_eq!(Duration::MAX.mul_f64_checked(2.0), None);
+
+        assert_eq!(Duration::MAX.mul_f64_saturating(2.0), Duration::MAX);
+        assert_eq!(2.seconds().mul_f64_saturating(-1.0), Duration::ZERO);
+        assert_eq!(2.seconds().mul_f64_saturating(f64::INFINITY), Duration::MAX);
+        assert_eq!(Duration::ZERO.mul_f64_saturating(f64::INFINITY), Duration::ZERO);
+        assert_eq!(Duration::ZERO.mul_f64_saturating(f64::MAX), Duration::ZERO);
+        assert_eq!(2.seconds().mul_f64_saturating(f64::NAN), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_div_duration() {
+        assert_eq!(90.minutes().div_duration(1.hours()), 1.5);
+        assert_eq!(Duration::ZERO.div_duration(1.seconds()), 0.0);
+        assert!(1.seconds().div_duration(Duration::ZERO).is_infinite());
+    }
+
+    #[test]
+    fn test_div_rem_duration() {
+        assert_eq!(10.seconds().div_rem_duration(3.seconds()), (3, 1.seconds()));
+        assert_eq!(Duration::MAX.div_rem_duration(1.nanoseconds()), (Duration::MAX.as_nanos(), Duration::ZERO));
+        assert_eq!(1.seconds().checked_div_rem_duration(Duration::ZERO), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "by a zero duration")]
+    fn test_div_rem_by_zero_panics() {
+        let _ = 1.seconds().div_rem_duration(Duration::ZERO);
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(30.seconds().percent(75), 22500.milliseconds());
+        assert_eq!(3.nanoseconds().percent(50), 1.nanoseconds());
+        assert_eq!(1.seconds().percent(250), 2500.milliseconds());
+        assert_eq!(Duration::MAX.percent(100), Duration::MAX);
+        assert_eq!(Duration::MAX.checked_percent(101), None);
+        assert_eq!(Duration::MAX.saturating_percent(u32::MAX), Duration::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows u64 seconds capacity")]
+    fn test_percent_panics_on_overflow() {
+        let _ = Duration::MAX.percent(200);
+    }
+
+    #[test]
+    fn test_halved_and_doubled() {
+        assert_eq!(3.seconds().halved(), 1500.milliseconds());
+        assert_eq!(1.nanoseconds().halved(), Duration::ZERO);
+        assert_eq!(3.seconds().doubled(), 6.seconds());
+        assert_eq!(Duration::MAX.checked_doubled(), None);
+        assert_eq!(Duration::MAX.saturating_doubled(), Duration::MAX);
+    }
+}
@@ -0,0 +1,149 @@
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use crate::Backoff;
+
+/// Upper bounds of latency histogram buckets, e.g. for Prometheus-style metrics.
+///
+/// Bucket `i` holds durations up to and including `bounds()[i]`, above the previous
+/// bound. One extra overflow bucket, index `len()`, catches everything above the last
+/// bound, so a histogram needs `len() + 1` counters.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{Buckets, DurationBucketExt, DurationExt};
+///
+/// let buckets = Buckets::exponential(1.milliseconds(), 2.0, 4);
+/// assert_eq!(buckets.bounds(), [1.milliseconds(), 2.milliseconds(), 4.milliseconds(), 8.milliseconds()]);
+///
+/// let mut counts = vec![0u64; buckets.len() + 1];
+/// for latency in [500.microseconds(), 3.milliseconds(), 4.milliseconds(), 1.seconds()] {
+///     counts[latency.bucket(&buckets)] += 1;
+/// }
+/// assert_eq!(counts, [1, 0, 2, 0, 1]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Buckets {
+    bounds: Vec<Duration>,
+}
+
+impl Buckets {
+    /// Creates `count` bounds starting at `start`, each `factor` times the previous one.
+    ///
+    /// Growth saturates at `Duration::MAX`; repeated saturated bounds are collapsed, so
+    /// there may be fewer than `count`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is not finite or not greater than `1.0`.
+    #[track_caller]
+    pub fn exponential(start: Duration, factor: f64, count: u32) -> Self {
+        assert!(
+            factor.is_finite() && factor > 1.0,
+            "bucket factor must be finite and greater than 1.0: got {}",
+            factor
+        );
+        let mut bounds: Vec<Duration> = Backoff::exponential(start).factor(factor).max_retries(count).collect();
+        bounds.dedup();
+        Buckets { bounds }
+    }
+
+    /// Creates `count` bounds starting at `start`, each `width` above the previous one.
+    ///
+    /// Bounds saturate at `Duration::MAX` and are collapsed like in
+    /// [`exponential`](Self::exponential).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is zero.
+    #[track_caller]
+    pub fn linear(start: Duration, width: Duration, count: u32) -> Self {
+        assert!(!width.is_zero(), "bucket width must be greater than zero");
+        let mut bounds: Vec<Duration> = (0..count).map(|i| start.saturating_add(width.saturating_mul(i))).collect();
+        bounds.dedup();
+        Buckets { bounds }
+    }
+
+    /// Returns the upper bounds, in increasing order.
+    pub fn bounds(&self) -> &[Duration] {
+        &self.bounds
+    }
+
+    /// Returns the number of bounds, not counting the overflow bucket.
+    pub fn len(&self) -> usize {
+        self.bounds.len()
+    }
+
+    /// Returns `true` if there are no bounds, so every duration lands in the overflow bucket.
+    pub fn is_empty(&self) -> bool {
+        self.bounds.is_empty()
+    }
+
+    /// Returns the index of the bucket that holds `duration`, `len()` for the overflow bucket.
+    pub fn index_of(&self, duration: Duration) -> usize {
+        self.bounds.partition_point(|&bound| bound < duration)
+    }
+}
+
+/// An extension trait that finds the histogram bucket of a `Duration`.
+///
+/// See [`Buckets`] for an example.
+pub trait DurationBucketExt {
+    /// Returns the index of the bucket that holds this duration, see [`Buckets::index_of`].
+    fn bucket(&self, buckets: &Buckets) -> usize;
+}
+
+impl DurationBucketExt for Duration {
+    fn bucket(&self, buckets: &Buckets) -> usize {
+        buckets.index_of(*self)
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_exponential_bounds() {
+        let buckets = Buckets::exponential(1.milliseconds(), 2.0, 16);
+        assert_eq!(buckets.len(), 16);
+        assert_eq!(buckets.bounds()[15], 32768.milliseconds());
+
+        let buckets = Buckets::exponential(100.milliseconds(), 1.5, 3);
+        assert_eq!(buckets.bounds(), [100.milliseconds(), 150.milliseconds(), 225.milliseconds()]);
+    }
+
+    #[test]
+    fn test_linear_bounds() {
+        let buckets = Buckets::linear(10.milliseconds(), 10.milliseconds(), 3);
+        assert_eq!(buckets.bounds(), [10.milliseconds(), 20.milliseconds(), 30.milliseconds()]);
+        assert!(Buckets::linear(1.seconds(), 1.seconds(), 0).is_empty());
+    }
+
+    #[test]
+    fn test_saturated_bounds_collapse() {
+        let start = u64::MAX.seconds() / 2 + 1.seconds();
+        let buckets = Buckets::exponential(start, 2.0, 10);
+        assert_eq!(buckets.bounds(), [start, Duration::MAX]);
+    }
+
+    #[test]
+    fn test_bucket_edges_are_inclusive() {
+        let buckets = Buckets::linear(10.milliseconds(), 10.milliseconds(), 3);
+        assert_eq!(Duration::ZERO.bucket(&buckets), 0);
+        assert_eq!(10.milliseconds().bucket(&buckets), 0);
+        assert_eq!((10.milliseconds() + 1.nanoseconds()).bucket(&buckets), 1);
+        assert_eq!(30.milliseconds().bucket(&buckets), 2);
+        assert_eq!(31.milliseconds().bucket(&buckets), 3);
+        assert_eq!(Duration::MAX.bucket(&buckets), buckets.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "bucket factor must be finite and greater than 1.0")]
+    fn test_factor_one_panics() {
+        let _ = Buckets::exponential(1.seconds(), 1.0, 4);
+    }
+}
@@ -0,0 +1,245 @@
+use core::fmt;
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+use core::time::Duration;
+
+use crate::value::{self, NANOS_PER_HOUR, NANOS_PER_MICRO, NANOS_PER_MILLI, NANOS_PER_MINUTE, NANOS_PER_SEC};
+
+/// A `Duration` that is guaranteed to be greater than zero.
+///
+/// APIs such as interval timers reject zero durations; taking a `NonZeroDuration`
+/// encodes that requirement in the type instead of a runtime check. Create one from
+/// the `NonZero*` integers with [`NonZeroDurationExt`], or check a `Duration` with
+/// [`NonZeroDuration::new`].
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, NonZeroDuration, NonZeroDurationExt};
+/// use std::num::NonZeroU32;
+/// use std::time::Duration;
+///
+/// fn every(period: NonZeroDuration) -> Duration {
+///     period.get()
+/// }
+///
+/// let five = NonZeroU32::new(5).unwrap();
+/// assert_eq!(every(five.seconds()), 5.seconds());
+/// assert_eq!(NonZeroDuration::new(Duration::ZERO), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonZeroDuration(Duration);
+
+impl NonZeroDuration {
+    /// The shortest non-zero duration, one nanosecond.
+    pub const MIN: NonZeroDuration = NonZeroDuration(Duration::from_nanos(1));
+    /// The longest representable duration.
+    pub const MAX: NonZeroDuration = NonZeroDuration(Duration::MAX);
+
+    /// Returns `duration` as a `NonZeroDuration`, or `None` if it is zero.
+    pub const fn new(duration: Duration) -> Option<Self> {
+        if duration.is_zero() {
+            None
+        } else {
+            Some(NonZeroDuration(duration))
+        }
+    }
+
+    /// Returns the contained `Duration`.
+    pub const fn get(self) -> Duration {
+        self.0
+    }
+
+    /// Adds two non-zero durations, returning `None` on overflow.
+    pub fn checked_add(self, other: NonZeroDuration) -> Option<NonZeroDuration> {
+        self.0.checked_add(other.0).map(NonZeroDuration)
+    }
+
+    /// Adds two non-zero durations, clamping to [`NonZeroDuration::MAX`] on overflow.
+    pub fn saturating_add(self, other: NonZeroDuration) -> NonZeroDuration {
+        NonZeroDuration(self.0.saturating_add(other.0))
+    }
+}
+
+impl From<NonZeroDuration> for Duration {
+    fn from(duration: NonZeroDuration) -> Self {
+        duration.0
+    }
+}
+
+impl TryFrom<Duration> for NonZeroDuration {
+    type Error = ZeroDurationError;
+
+    fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+        NonZeroDuration::new(duration).ok_or(ZeroDurationError)
+    }
+}
+
+/// The error returned when converting a zero `Duration` into a [`NonZeroDuration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ZeroDurationError;
+
+impl fmt::Display for ZeroDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("duration must be greater than zero")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ZeroDurationError {}
+
+/// An extension trait that creates a [`NonZeroDuration`] from the unsigned `NonZero*` integers.
+///
+/// Mirrors [`DurationExt`](crate::DurationExt): a non-zero count of any unit is a
+/// non-zero duration, so only overflow can fail.
+///
+/// # Panics
+///
+/// Panics if the resulting seconds exceed `u64::MAX`. Every method has a `checked_*`
+/// counterpart that returns `None` instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, NonZeroDurationExt};
+/// use std::num::NonZeroU64;
+///
+/// let retries = NonZeroU64::new(3).unwrap();
+/// assert_eq!(retries.minutes().get(), 3.minutes());
+/// assert_eq!(NonZeroU64::MAX.checked_hours(), None);
+/// ```
+pub trait NonZeroDurationExt {
+    /// Creates a `NonZeroDuration` from the specified number of seconds.
+    fn seconds(self) -> NonZeroDuration;
+    /// Creates a `NonZeroDuration` from the specified number of minutes.
+    fn minutes(self) -> NonZeroDuration;
+    /// Creates a `NonZeroDuration` from the specified number of hours.
+    fn hours(self) -> NonZeroDuration;
+    /// Creates a `NonZeroDuration` from the specified number of milliseconds.
+    fn milliseconds(self) -> NonZeroDuration;
+    /// Creates a `NonZeroDuration` from the specified number of microseconds.
+    fn microseconds(self) -> NonZeroDuration;
+    /// Creates a `NonZeroDuration` from the specified number of nanoseconds.
+    fn nanoseconds(self) -> NonZeroDuration;
+    /// Like [`seconds`](Self::seconds), but returns `None` on overflow.
+    fn checked_seconds(self) -> Option<NonZeroDuration>;
+    /// Like [`minutes`](Self::minutes), but returns `None` on overflow.
+    fn checked_minutes(self) -> Option<NonZeroDuration>;
+    /// Like [`hours`](Self::hours), but returns `None` on overflow.
+    fn checked_hours(self) -> Option<NonZeroDuration>;
+    /// Like [`milliseconds`](Self::milliseconds), but returns `None` on overflow.
+    fn checked_milliseconds(self) -> Option<NonZeroDuration>;
+    /// Like [`microseconds`](Self::microseconds), but returns `None` on overflow.
+    fn checked_microseconds(self) -> Option<NonZeroDuration>;
+    /// Like [`nanoseconds`](Self::nanoseconds), but returns `None` on overflow.
+    fn checked_nanoseconds(self) -> Option<NonZeroDuration>;
+}
+
+macro_rules! impl_nonzero {
+    ($($t:ty),*) => {$(
+        impl NonZeroDurationExt for $t {
+            #[track_caller]
+            fn seconds(self) -> NonZeroDuration {
+                NonZeroDuration(value::to_duration(self.get(), NANOS_PER_SEC, "seconds"))
+            }
+
+            #[track_caller]
+            fn minutes(self) -> NonZeroDuration {
+                NonZeroDuration(value::to_duration(self.get(), NANOS_PER_MINUTE, "minutes"))
+            }
+
+            #[track_caller]
+            fn hours(self) -> NonZeroDuration {
+                NonZeroDuration(value::to_duration(self.get(), NANOS_PER_HOUR, "hours"))
+            }
+
+            #[track_caller]
+            fn milliseconds(self) -> NonZeroDuration {
+                NonZeroDuration(value::to_duration(self.get(), NANOS_PER_MILLI, "milliseconds"))
+            }
+
+            #[track_caller]
+            fn microseconds(self) -> NonZeroDuration {
+                NonZeroDuration(value::to_duration(self.get(), NANOS_PER_MICRO, "microseconds"))
+            }
+
+            #[track_caller]
+            fn nanoseconds(self) -> NonZeroDuration {
+                NonZeroDuration(value::to_duration(self.get(), 1, "nanoseconds"))
+            }
+
+            fn checked_seconds(self) -> Option<NonZeroDuration> {
+                crate::DurationExt::checked_seconds(self.get()).map(NonZeroDuration)
+            }
+
+            fn checked_minutes(self) -> Option<NonZeroDuration> {
+                crate::DurationExt::checked_minutes(self.get()).map(NonZeroDuration)
+            }
+
+            fn checked_hours(self) -> Option<NonZeroDuration> {
+                crate::DurationExt::checked_hours(self.get()).map(NonZeroDuration)
+            }
+
+            fn checked_milliseconds(self) -> Option<NonZeroDuration> {
+                crate::DurationExt::checked_milliseconds(self.get()).map(NonZeroDuration)
+            }
+
+            fn checked_microseconds(self) -> Option<NonZeroDuration> {
+                crate::DurationExt::checked_microseconds(self.get()).map(NonZeroDuration)
+            }
+
+            fn checked_nanoseconds(self) -> Option<NonZeroDuration> {
+                crate::DurationExt::checked_nanoseconds(self.get()).map(NonZeroDuration)
+            }
+        }
+    )*};
+}
+
+impl_nonzero!(NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize);
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nz(value: u32) -> NonZeroU32 {
+        NonZeroU32::new(value).unwrap()
+    }
+
+    #[test]
+    fn test_every_unit() {
+        assert_eq!(nz(7).seconds().get(), Duration::from_secs(7));
+        assert_eq!(nz(7).minutes().get(), Duration::from_secs(7 * 60));
+        assert_eq!(nz(7).hours().get(), Duration::from_secs(7 * 3600));
+        assert_eq!(nz(7).milliseconds().get(), Duration::from_millis(7));
+        assert_eq!(nz(7).microseconds().get(), Duration::from_micros(7));
+        assert_eq!(NonZeroU8::MIN.nanoseconds(), NonZeroDuration::MIN);
+    }
+
+    #[test]
+    fn test_checked_overflow() {
+        assert_eq!(NonZeroU64::MAX.checked_minutes(), None);
+        assert_eq!(NonZeroU128::MAX.checked_milliseconds(), None);
+        assert_eq!(NonZeroU64::MAX.checked_seconds().map(NonZeroDuration::get), Some(Duration::from_secs(u64::MAX)));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows u64 seconds capacity")]
+    fn test_overflow_panics() {
+        let _ = NonZeroU64::MAX.hours();
+    }
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(NonZeroDuration::new(Duration::ZERO), None);
+        assert_eq!(NonZeroDuration::try_from(Duration::ZERO), Err(ZeroDurationError));
+        let one = NonZeroDuration::try_from(Duration::from_secs(1)).unwrap();
+        assert_eq!(Duration::from(one), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_addition() {
+        assert_eq!(NonZeroDuration::MIN.checked_add(NonZeroDuration::MIN).map(NonZeroDuration::get), Some(Duration::from_nanos(2)));
+        assert_eq!(NonZeroDuration::MAX.checked_add(NonZeroDuration::MIN), None);
+        assert_eq!(NonZeroDuration::MAX.saturating_add(NonZeroDuration::MIN), NonZeroDuration::MAX);
+    }
+}
@@ -0,0 +1,139 @@
+use core::time::Duration;
+
+use crate::value::{self, IntoDurationValue, ValueError, NANOS_PER_HOUR, NANOS_PER_MICRO, NANOS_PER_MILLI, NANOS_PER_MINUTE, NANOS_PER_SEC};
+
+/// What a `*_with` constructor does when a value does not fit into a `Duration`.
+///
+/// The plain constructors such as `.minutes()` always panic and the `checked_*` ones
+/// always return `None`; this policy lets each call site pick its own behaviour.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Overflow {
+    /// Panic with the same message as the plain constructors.
+    #[default]
+    Panic,
+    /// Clamp overflowing values to `Duration::MAX` and negative values to `Duration::ZERO`.
+    ///
+    /// NaN and infinite floats have no sensible clamp and are still returned as errors.
+    Saturate,
+    /// Return the [`ValueError`] describing the problem.
+    Error,
+}
+
+/// An extension trait with constructors whose failure behaviour is chosen at the call
+/// site through an [`Overflow`] policy.
+///
+/// Every method returns a `Result`; with [`Overflow::Panic`] it is always `Ok`, and with
+/// [`Overflow::Saturate`] it is `Ok` unless a float is NaN or infinite.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, DurationPolicyExt, Overflow, ValueError};
+/// use std::time::Duration;
+///
+/// assert_eq!(5.minutes_with(Overflow::Panic), Ok(5.minutes()));
+/// assert_eq!(u64::MAX.minutes_with(Overflow::Saturate), Ok(Duration::MAX));
+/// assert_eq!((-5).minutes_with(Overflow::Saturate), Ok(Duration::ZERO));
+/// assert_eq!(u64::MAX.minutes_with(Overflow::Error), Err(ValueError::Overflow));
+/// ```
+pub trait DurationPolicyExt {
+    /// Creates a `Duration` from the specified number of seconds under `policy`.
+    fn seconds_with(self, policy: Overflow) -> Result<Duration, ValueError>;
+    /// Creates a `Duration` from the specified number of minutes under `policy`.
+    fn minutes_with(self, policy: Overflow) -> Result<Duration, ValueError>;
+    /// Creates a `Duration` from the specified number of hours under `policy`.
+    fn hours_with(self, policy: Overflow) -> Result<Duration, ValueError>;
+    /// Creates a `Duration` from the specified number of milliseconds under `policy`.
+    fn milliseconds_with(self, policy: Overflow) -> Result<Duration, ValueError>;
+    /// Creates a `Duration` from the specified number of microseconds under `policy`.
+    fn microseconds_with(self, policy: Overflow) -> Result<Duration, ValueError>;
+    /// Creates a `Duration` from the specified number of nanoseconds under `policy`.
+    fn nanoseconds_with(self, policy: Overflow) -> Result<Duration, ValueError>;
+}
+
+#[track_caller]
+fn convert<T: IntoDurationValue>(value: T, nanos_per_unit: u64, unit: &str, policy: Overflow) -> Result<Duration, ValueError> {
+    match policy {
+        Overflow::Panic => Ok(value::to_duration(value, nanos_per_unit, unit)),
+        Overflow::Saturate => match value.checked_units(nanos_per_unit) {
+            Err(ValueError::Overflow) => Ok(Duration::MAX),
+            Err(ValueError::Negative) => Ok(Duration::ZERO),
+            result => result,
+        },
+        Overflow::Error => value.checked_units(nanos_per_unit),
+    }
+}
+
+impl<T: IntoDurationValue> DurationPolicyExt for T {
+    #[track_caller]
+    fn seconds_with(self, policy: Overflow) -> Result<Duration, ValueError> {
+        convert(self, NANOS_PER_SEC, "seconds", policy)
+    }
+
+    #[track_caller]
+    fn minutes_with(self, policy: Overflow) -> Result<Duration, ValueError> {
+        convert(self, NANOS_PER_MINUTE, "minutes", policy)
+    }
+
+    #[track_caller]
+    fn hours_with(self, policy: Overflow) -> Result<Duration, ValueError> {
+        convert(self, NANOS_PER_HOUR, "hours", policy)
+    }
+
+    #[track_caller]
+    fn milliseconds_with(self, policy: Overflow) -> Result<Duration, ValueError> {
+        convert(self, NANOS_PER_MILLI, "milliseconds", policy)
+    }
+
+    #[track_caller]
+    fn microseconds_with(self, policy: Overflow) -> Result<Duration, ValueError> {
+        convert(self, NANOS_PER_MICRO, "microseconds", policy)
+    }
+
+    #[track_caller]
+    fn nanoseconds_with(self, policy: Overflow) -> Result<Duration, ValueError> {
+        convert(self, 1, "nanoseconds", policy)
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_valid_values_ignore_policy() {
+        for policy in [Overflow::Panic, Overflow::Saturate, Overflow::Error] {
+            assert_eq!(90.seconds_with(policy), Ok(90.seconds()));
+            assert_eq!(1.5.hours_with(policy), Ok(90.minutes()));
+            assert_eq!(7u8.nanoseconds_with(policy), Ok(7.nanoseconds()));
+        }
+    }
+
+    #[test]
+    fn test_saturate() {
+        assert_eq!(u64::MAX.hours_with(Overflow::Saturate), Ok(Duration::MAX));
+        assert_eq!(u128::MAX.milliseconds_with(Overflow::Saturate), Ok(Duration::MAX));
+        assert_eq!((-1).seconds_with(Overflow::Saturate), Ok(Duration::ZERO));
+        assert_eq!(f64::NAN.seconds_with(Overflow::Saturate), Err(ValueError::NotFinite));
+    }
+
+    #[test]
+    fn test_error() {
+        assert_eq!(u64::MAX.minutes_with(Overflow::Error), Err(ValueError::Overflow));
+        assert_eq!((-1).microseconds_with(Overflow::Error), Err(ValueError::Negative));
+        assert_eq!(f32::INFINITY.seconds_with(Overflow::Error), Err(ValueError::NotFinite));
+    }
+
+    #[test]
+    #[should_panic(expected = "duration value 18446744073709551615 hours overflows u64 seconds capacity")]
+    fn test_panic() {
+        let _ = u64::MAX.hours_with(Overflow::Panic);
+    }
+
+    #[test]
+    fn test_default_policy_panics() {
+        assert_eq!(Overflow::default(), Overflow::Panic);
+    }
+}
@@ -0,0 +1,306 @@
+use core::fmt;
+use core::ops::Deref;
+use core::str::FromStr;
+use core::time::Duration;
+
+use crate::display::{write_human, Labels};
+use crate::grammar::{self, GrammarError};
+
+/// An error returned when a duration string cannot be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseDurationError {
+    /// The input was empty or contained only whitespace.
+    Empty,
+    /// A component did not start with a valid number, e.g. `"h"` or `"1.s"`.
+    InvalidNumber,
+    /// A number was not followed by a unit, e.g. `"30"`.
+    MissingUnit,
+    /// A number was followed by an unrecognized unit, e.g. `"30x"`.
+    UnknownUnit,
+    /// The input started with a minus sign, e.g. `"-5s"`.
+    Negative,
+    /// The total exceeds the capacity of `Duration`.
+    Overflow,
+    /// The input does not follow the expected layout, e.g. an ISO 8601 string without `P`.
+    InvalidFormat,
+    /// The input uses months or years, which have no fixed length.
+    CalendarUnit,
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ParseDurationError::Empty => GrammarError::Empty.message(),
+            ParseDurationError::InvalidNumber => GrammarError::InvalidNumber.message(),
+            ParseDurationError::MissingUnit => GrammarError::MissingUnit.message(),
+            ParseDurationError::UnknownUnit => GrammarError::UnknownUnit.message(),
+            ParseDurationError::Negative => GrammarError::Negative.message(),
+            ParseDurationError::Overflow => GrammarError::Overflow.message(),
+            ParseDurationError::InvalidFormat => "invalid duration string format",
+            ParseDurationError::CalendarUnit => "months and years have no fixed length",
+        };
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseDurationError {}
+
+impl From<GrammarError> for ParseDurationError {
+    fn from(error: GrammarError) -> Self {
+        match error {
+            GrammarError::Empty => ParseDurationError::Empty,
+            GrammarError::InvalidNumber => ParseDurationError::InvalidNumber,
+            GrammarError::MissingUnit => ParseDurationError::MissingUnit,
+            GrammarError::UnknownUnit => ParseDurationError::UnknownUnit,
+            GrammarError::Negative => ParseDurationError::Negative,
+            GrammarError::Overflow => ParseDurationError::Overflow,
+        }
+    }
+}
+
+/// Parses a human-readable duration string such as `"1h30m45s"`, `"500ms"` or `"2d 4h"`.
+///
+/// The input is a sequence of `<number><unit>` components, optionally separated by
+/// whitespace. Numbers may have a fractional part (`"1.5h"`); anything finer than a
+/// nanosecond is truncated. A lone `"0"` is accepted without a unit.
+///
+/// | Unit | Accepted spellings |
+/// |------|--------------------|
+/// | nanoseconds | `ns`, `nsec`, `nanos` |
+/// | microseconds | `us`, `µs`, `usec`, `micros` |
+/// | milliseconds | `ms`, `msec`, `millis` |
+/// | seconds | `s`, `sec`, `secs`, `second`, `seconds` |
+/// | minutes | `m`, `min`, `mins`, `minute`, `minutes` |
+/// | hours | `h`, `hr`, `hrs`, `hour`, `hours` |
+/// | days | `d`, `day`, `days` |
+/// | weeks | `w`, `week`, `weeks` |
+///
+/// Days and weeks are **fixed spans** of 24 and 168 hours. They are accepted because
+/// configuration values like `"2d"` are common, but they carry no calendar meaning.
+///
+/// # Errors
+///
+/// Returns a [`ParseDurationError`] if the input is empty, malformed, negative, or
+/// exceeds the capacity of `Duration`.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{parse_duration, DurationExt};
+///
+/// assert_eq!(parse_duration("1h30m45s"), Ok(1.hours() + 30.minutes() + 45.seconds()));
+/// assert_eq!(parse_duration("500ms"), Ok(500.milliseconds()));
+/// assert_eq!(parse_duration("2d 4h"), Ok((2 * 24 + 4).hours()));
+/// assert!(parse_duration("-5s").is_err());
+/// ```
+pub fn parse_duration(input: &str) -> Result<Duration, ParseDurationError> {
+    let (secs, nanos) = grammar::parse(input)?;
+    Ok(Duration::new(secs, nanos))
+}
+
+/// A type that can be parsed from a human-readable duration string.
+///
+/// This is the string counterpart to the fluent constructors, see [`parse_duration`]
+/// for the accepted syntax.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, FromHumanStr};
+/// use std::time::Duration;
+///
+/// let timeout = Duration::from_human_str("1m30s").unwrap();
+/// assert_eq!(timeout, 90.seconds());
+/// ```
+pub trait FromHumanStr: Sized {
+    /// Parses a human-readable duration string such as `"1h30m"`.
+    fn from_human_str(input: &str) -> Result<Self, ParseDurationError>;
+}
+
+impl FromHumanStr for Duration {
+    fn from_human_str(input: &str) -> Result<Self, ParseDurationError> {
+        parse_duration(input)
+    }
+}
+
+/// A `Duration` that parses from and displays as a human-readable string, e.g. for CLI arguments.
+///
+/// Implements `FromStr` with the syntax of [`parse_duration`], so argument parsers that
+/// work with `FromStr` types, such as clap's derive API, accept `--timeout 30s` or
+/// `--retention 90d` directly. `Display` writes every non-zero component down to
+/// nanoseconds (e.g. `"1d 2h 500ms"`), which parses back to the same value; this
+/// keeps default values in help output exact.
+///
+/// # Use with clap
+///
+/// There is no `clap` feature or dedicated value parser: clap's derive API falls back
+/// to `FromStr` for any `Clone + Send + Sync` type whose error implements
+/// `std::error::Error`, which `HumanDuration` satisfies with the `std` feature. Declare the
+/// field as `HumanDuration` and no `value_parser` attribute is needed:
+///
+/// ```text
+/// #[derive(clap::Parser)]
+/// struct Args {
+///     #[arg(long, default_value = "30s")]
+///     timeout: HumanDuration,
+/// }
+///
+/// let timeout: Duration = args.timeout.into();
+/// ```
+///
+/// For a plain `Duration` field, `#[arg(value_parser = parse_duration)]` works too, since
+/// clap accepts any `Fn(&str) -> Result<T, E>` as a value parser.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, HumanDuration};
+/// use std::time::Duration;
+///
+/// let retention: HumanDuration = "90d".parse().unwrap();
+/// assert_eq!(*retention, (90 * 24).hours());
+///
+/// let timeout = HumanDuration::from(1.minutes() + 500.milliseconds());
+/// assert_eq!(timeout.to_string(), "1m 500ms");
+/// assert_eq!(Duration::from(timeout.to_string().parse::<HumanDuration>().unwrap()), 60500.milliseconds());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct HumanDuration(pub Duration);
+
+impl FromStr for HumanDuration {
+    type Err = ParseDurationError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_duration(input).map(HumanDuration)
+    }
+}
+
+impl fmt::Display for HumanDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_human(f, self.0, true, Labels::Short)
+    }
+}
+
+impl Deref for HumanDuration {
+    type Target = Duration;
+
+    fn deref(&self) -> &Duration {
+        &self.0
+    }
+}
+
+impl From<Duration> for HumanDuration {
+    fn from(duration: Duration) -> Self {
+        HumanDuration(duration)
+    }
+}
+
+impl From<HumanDuration> for Duration {
+    fn from(duration: HumanDuration) -> Self {
+        duration.0
+    }
+}
+
+/// Converts a total number of nanoseconds into a `Duration`, checking its capacity.
+pub(crate) fn total_to_duration(total: u128) -> Result<Duration, ParseDurationError> {
+    let (secs, nanos) = grammar::split_total(total)?;
+    Ok(Duration::new(secs, nanos))
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_parse_compound() {
+        assert_eq!(parse_duration("1h30m45s"), Ok(1.hours() + 30.minutes() + 45.seconds()));
+        assert_eq!(parse_duration("2d 4h"), Ok(52.hours()));
+        assert_eq!(parse_duration("1w"), Ok((7 * 24).hours()));
+    }
+
+    #[test]
+    fn test_parse_small_units() {
+        assert_eq!(parse_duration("500ms"), Ok(500.milliseconds()));
+        assert_eq!(parse_duration("250us"), Ok(250.microseconds()));
+        assert_eq!(parse_duration("250µs"), Ok(250.microseconds()));
+        assert_eq!(parse_duration("7ns"), Ok(7.nanoseconds()));
+    }
+
+    #[test]
+    fn test_parse_long_unit_names_and_spacing() {
+        assert_eq!(parse_duration("  2 hours 5 minutes "), Ok(2.hours() + 5.minutes()));
+        assert_eq!(parse_duration("1 sec"), Ok(1.seconds()));
+    }
+
+    #[test]
+    fn test_parse_fractions() {
+        assert_eq!(parse_duration("1.5h"), Ok(90.minutes()));
+        assert_eq!(parse_duration("0.5s"), Ok(500.milliseconds()));
+        assert_eq!(parse_duration(".25s"), Ok(250.milliseconds()));
+        assert_eq!(parse_duration("1.0000000009s"), Ok(1.seconds()));
+    }
+
+    #[test]
+    fn test_parse_zero() {
+        assert_eq!(parse_duration("0"), Ok(Duration::ZERO));
+        assert_eq!(parse_duration("0s"), Ok(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse_duration(""), Err(ParseDurationError::Empty));
+        assert_eq!(parse_duration("   "), Err(ParseDurationError::Empty));
+        assert_eq!(parse_duration("h"), Err(ParseDurationError::InvalidNumber));
+        assert_eq!(parse_duration("1.s"), Err(ParseDurationError::InvalidNumber));
+        assert_eq!(parse_duration("30"), Err(ParseDurationError::MissingUnit));
+        assert_eq!(parse_duration("1h30"), Err(ParseDurationError::MissingUnit));
+        assert_eq!(parse_duration("30x"), Err(ParseDurationError::UnknownUnit));
+        assert_eq!(parse_duration("-5s"), Err(ParseDurationError::Negative));
+    }
+
+    #[test]
+    fn test_parse_overflow() {
+        assert_eq!(parse_duration("18446744073709551615s"), Ok(Duration::from_secs(u64::MAX)));
+        assert_eq!(parse_duration("18446744073709551616s"), Err(ParseDurationError::Overflow));
+        assert_eq!(parse_duration("99999999999999999999999999999999999999999h"), Err(ParseDurationError::Overflow));
+    }
+
+    #[test]
+    fn test_from_human_str() {
+        assert_eq!(Duration::from_human_str("1m30s"), Ok(90.seconds()));
+    }
+
+    #[test]
+    fn test_human_duration_from_str() {
+        assert_eq!("30s".parse::<HumanDuration>(), Ok(HumanDuration(30.seconds())));
+        assert_eq!("90d".parse::<HumanDuration>().map(Duration::from), Ok((90 * 24).hours()));
+        assert_eq!("30x".parse::<HumanDuration>(), Err(ParseDurationError::UnknownUnit));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_human_duration_display_round_trip() {
+        use alloc::string::ToString;
+
+        assert_eq!(HumanDuration(Duration::ZERO).to_string(), "0s");
+        assert_eq!(HumanDuration(26.hours() + 1.nanoseconds()).to_string(), "1d 2h 1ns");
+        for duration in [Duration::ZERO, 1.nanoseconds(), 90.minutes() + 7.microseconds(), Duration::MAX] {
+            let text = HumanDuration(duration).to_string();
+            assert_eq!(text.parse(), Ok(HumanDuration(duration)), "{}", text);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "macros")]
+    fn test_duration_macro_matches_runtime_parser() {
+        assert_eq!(Ok(crate::duration!("1h 30m 500ms")), parse_duration("1h 30m 500ms"));
+        assert_eq!(Ok(crate::duration!("2d 4h")), parse_duration("2d 4h"));
+        assert_eq!(Ok(crate::duration!("1.0000000009s")), parse_duration("1.0000000009s"));
+        assert_eq!(Ok(crate::duration!("250µs")), parse_duration("250µs"));
+        assert_eq!(Ok(crate::duration!("0")), parse_duration("0"));
+    }
+}
@@ -0,0 +1,208 @@
+use std::cell::Cell;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+use crate::value;
+
+thread_local! {
+    static STATE: Cell<u64> = Cell::new(seed());
+}
+
+/// Seeds the generator from the per-process random keys of `RandomState`.
+fn seed() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_usize(&hasher as *const _ as usize);
+    hasher.finish()
+}
+
+/// Returns the next value of a thread-local SplitMix64 generator.
+///
+/// Good enough to decorrelate retries; not suitable for anything security related.
+pub(crate) fn next_u64() -> u64 {
+    STATE.with(|state| {
+        let next = state.get().wrapping_add(0x9E37_79B9_7F4A_7C15);
+        state.set(next);
+        let mut z = next;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    })
+}
+
+/// Returns a uniformly distributed value in `[0.0, 1.0)`.
+pub(crate) fn next_f64() -> f64 {
+    (next_u64() >> 11) as f64 / (1u64 << 53) as f64
+}
+
+/// Returns a uniformly distributed value in `[0, range)` drawn from `rng`, without
+/// modulo bias.
+fn next_u128_below<R: FnMut() -> u64>(rng: &mut R, range: u128) -> u128 {
+    // `2^128 % range` low draws are rejected so every residue is equally likely.
+    let threshold = range.wrapping_neg() % range;
+    loop {
+        let value = u128::from(rng()) << 64 | u128::from(rng());
+        if value >= threshold {
+            return value % range;
+        }
+    }
+}
+
+/// A uniform distribution over the durations between two bounds, inclusive.
+///
+/// Sampling works on the full `u128` nanosecond range, so even spans far longer than
+/// `u64::MAX` nanoseconds (about 584 years) are covered evenly, down to the nanosecond.
+/// [`sample`](Self::sample) uses the same thread-local generator as
+/// [`JitterExt`](crate::JitterExt), which is not cryptographically secure.
+/// [`sample_with`](Self::sample_with) takes any source of uniform `u64`s instead, e.g. a
+/// seeded generator for reproducible tests or `|| rng.next_u64()` from the `rand` crate.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, UniformDuration};
+///
+/// let heartbeat = UniformDuration::new(25.seconds(), 35.seconds());
+/// for _ in 0..100 {
+///     let interval = heartbeat.sample();
+///     assert!(interval >= 25.seconds() && interval <= 35.seconds());
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UniformDuration {
+    low: u128,
+    span: u128,
+}
+
+impl UniformDuration {
+    /// Creates a distribution over `min..=max`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`.
+    #[track_caller]
+    pub fn new(min: Duration, max: Duration) -> Self {
+        assert!(min <= max, "uniform duration bounds are out of order: {:?} > {:?}", min, max);
+        UniformDuration {
+            low: min.as_nanos(),
+            span: max.as_nanos() - min.as_nanos(),
+        }
+    }
+
+    /// Returns a random duration between the bounds, inclusive.
+    pub fn sample(&self) -> Duration {
+        self.sample_with(&mut next_u64)
+    }
+
+    /// Returns a random duration between the bounds, inclusive, drawing from `rng`.
+    ///
+    /// `rng` must return uniformly distributed `u64`s; it is called at least twice.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use duration_extender::{DurationExt, UniformDuration};
+    ///
+    /// // A fixed sequence makes the result reproducible.
+    /// let mut state = 0u64;
+    /// let mut rng = || {
+    ///     state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    ///     state
+    /// };
+    /// let uniform = UniformDuration::new(1.seconds(), 2.seconds());
+    /// let delay = uniform.sample_with(&mut rng);
+    /// assert!(delay >= 1.seconds() && delay <= 2.seconds());
+    /// ```
+    pub fn sample_with<R: FnMut() -> u64>(&self, rng: &mut R) -> Duration {
+        // `span` is below 2^95, so `span + 1` can't overflow.
+        let nanos = self.low + next_u128_below(rng, self.span + 1);
+        value::duration_from_nanos(nanos).expect("sample lies between two valid durations")
+    }
+}
+
+/// Returns a random duration between `min` and `max`, inclusive.
+///
+/// Shorthand for [`UniformDuration::new(min, max).sample()`](UniformDuration::sample);
+/// build the distribution once when sampling repeatedly.
+///
+/// # Panics
+///
+/// Panics if `min > max`.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{random_between, DurationExt};
+///
+/// let delay = random_between(1.seconds(), 5.seconds());
+/// assert!(delay >= 1.seconds() && delay <= 5.seconds());
+/// ```
+#[track_caller]
+pub fn random_between(min: Duration, max: Duration) -> Duration {
+    UniformDuration::new(min, max).sample()
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_f64_in_unit_range() {
+        for _ in 0..10_000 {
+            let value = next_f64();
+            assert!((0.0..1.0).contains(&value));
+        }
+    }
+
+    #[test]
+    fn test_next_u64_varies() {
+        assert_ne!(next_u64(), next_u64());
+    }
+
+    #[test]
+    fn test_next_u128_below_stays_in_range() {
+        assert_eq!(next_u128_below(&mut next_u64, 1), 0);
+        for _ in 0..10_000 {
+            assert!(next_u128_below(&mut next_u64, 3) < 3);
+        }
+        let range = u128::MAX / 3 * 2;
+        assert!(next_u128_below(&mut next_u64, range) < range);
+    }
+
+    #[test]
+    fn test_sample_covers_small_range() {
+        let uniform = UniformDuration::new(Duration::from_nanos(10), Duration::from_nanos(13));
+        let mut seen = [false; 4];
+        for _ in 0..1_000 {
+            seen[(uniform.sample().as_nanos() - 10) as usize] = true;
+        }
+        assert_eq!(seen, [true; 4]);
+    }
+
+    #[test]
+    fn test_sample_full_range() {
+        assert_eq!(random_between(Duration::MAX, Duration::MAX), Duration::MAX);
+        let uniform = UniformDuration::new(Duration::ZERO, Duration::MAX);
+        // Beyond `u64::MAX` nanoseconds is all but certain over the full range.
+        assert!((0..100).any(|_| uniform.sample() > Duration::from_nanos(u64::MAX)));
+    }
+
+    #[test]
+    fn test_sample_with_uses_given_rng() {
+        let uniform = UniformDuration::new(Duration::from_nanos(10), Duration::from_nanos(13));
+        assert_eq!(uniform.sample_with(&mut || 0), Duration::from_nanos(10));
+        assert_eq!(uniform.sample_with(&mut || 3), Duration::from_nanos(13));
+        assert_eq!(uniform.sample_with(&mut || 5), Duration::from_nanos(11));
+
+        // The full range combines both draws into one 128-bit value.
+        let full = UniformDuration::new(Duration::ZERO, Duration::MAX);
+        assert!(full.sample_with(&mut || u64::MAX) > Duration::from_nanos(u64::MAX));
+    }
+
+    #[test]
+    #[should_panic(expected = "uniform duration bounds are out of order")]
+    fn test_reversed_bounds_panic() {
+        let _ = random_between(Duration::from_secs(2), Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,165 @@
+use core::iter::FusedIterator;
+use core::time::Duration;
+
+/// An iterator over evenly spaced durations, created by [`DurationStepExt`].
+///
+/// Stops early instead of overflowing if the next value would exceed `Duration::MAX`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurationRange {
+    next: Option<Duration>,
+    end: Duration,
+    step: Duration,
+    inclusive: bool,
+}
+
+impl DurationRange {
+    #[track_caller]
+    fn new(start: Duration, end: Duration, step: Duration, inclusive: bool) -> Self {
+        assert!(!step.is_zero(), "duration range step must be greater than zero");
+        DurationRange { next: Some(start), end, step, inclusive }
+    }
+
+    fn contains(&self, value: Duration) -> bool {
+        if self.inclusive {
+            value <= self.end
+        } else {
+            value < self.end
+        }
+    }
+}
+
+impl Iterator for DurationRange {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        let current = self.next.filter(|&value| self.contains(value))?;
+        self.next = current.checked_add(self.step);
+        Some(current)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = match self.next.filter(|&value| self.contains(value)) {
+            Some(current) => {
+                let span = (self.end - current).as_nanos();
+                let step = self.step.as_nanos();
+                let mut count = span / step;
+                if !self.inclusive && span.is_multiple_of(step) {
+                    count -= 1;
+                }
+                // Capped by the values left before `Duration::MAX`.
+                let headroom = (Duration::MAX - current).as_nanos() / step;
+                count.min(headroom) + 1
+            }
+            None => 0,
+        };
+        match usize::try_from(remaining) {
+            Ok(remaining) => (remaining, Some(remaining)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+impl FusedIterator for DurationRange {}
+
+/// An extension trait that steps from one duration to another, e.g. to sweep polling
+/// intervals or generate benchmark workloads.
+///
+/// std ranges do not implement iteration for `Duration`, so this fills the gap:
+/// [`step_until`](Self::step_until) excludes the end like `a..b`, and
+/// [`step_through`](Self::step_through) includes it like `a..=b`.
+///
+/// # Panics
+///
+/// Panics if `step` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, DurationStepExt};
+///
+/// let intervals: Vec<_> = 1.seconds().step_until(3.seconds(), 500.milliseconds()).collect();
+/// assert_eq!(intervals, [1.seconds(), 1500.milliseconds(), 2.seconds(), 2500.milliseconds()]);
+///
+/// assert_eq!(1.seconds().step_through(3.seconds(), 1.seconds()).count(), 3);
+/// ```
+pub trait DurationStepExt {
+    /// Returns the durations from this one up to, but excluding, `end`, `step` apart.
+    fn step_until(self, end: Duration, step: Duration) -> DurationRange;
+    /// Returns the durations from this one up to and including `end`, `step` apart.
+    fn step_through(self, end: Duration, step: Duration) -> DurationRange;
+}
+
+impl DurationStepExt for Duration {
+    #[track_caller]
+    fn step_until(self, end: Duration, step: Duration) -> DurationRange {
+        DurationRange::new(self, end, step, false)
+    }
+
+    #[track_caller]
+    fn step_through(self, end: Duration, step: Duration) -> DurationRange {
+        DurationRange::new(self, end, step, true)
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_step_until_excludes_end() {
+        let values: Vec<_> = 1.seconds().step_until(10.seconds(), 500.milliseconds()).collect();
+        assert_eq!(values.len(), 18);
+        assert_eq!(values.first(), Some(&1.seconds()));
+        assert_eq!(values.last(), Some(&9500.milliseconds()));
+    }
+
+    #[test]
+    fn test_step_through_includes_end() {
+        let values: Vec<_> = Duration::ZERO.step_through(3.seconds(), 1.seconds()).collect();
+        assert_eq!(values, [Duration::ZERO, 1.seconds(), 2.seconds(), 3.seconds()]);
+
+        let uneven: Vec<_> = Duration::ZERO.step_through(5.seconds(), 2.seconds()).collect();
+        assert_eq!(uneven, [Duration::ZERO, 2.seconds(), 4.seconds()]);
+    }
+
+    #[test]
+    fn test_empty_ranges() {
+        assert_eq!(5.seconds().step_until(5.seconds(), 1.seconds()).next(), None);
+        assert_eq!(6.seconds().step_through(5.seconds(), 1.seconds()).next(), None);
+        assert_eq!(5.seconds().step_through(5.seconds(), 1.seconds()).count(), 1);
+    }
+
+    #[test]
+    fn test_stops_before_overflow() {
+        let start = Duration::MAX - 1.seconds();
+        let values: Vec<_> = start.step_through(Duration::MAX, 600.milliseconds()).collect();
+        assert_eq!(values, [start, start + 600.milliseconds()]);
+    }
+
+    #[test]
+    fn test_size_hint_is_exact() {
+        let cases = [
+            1.seconds().step_until(10.seconds(), 500.milliseconds()),
+            Duration::ZERO.step_through(5.seconds(), 2.seconds()),
+            (Duration::MAX - 1.seconds()).step_through(Duration::MAX, 600.milliseconds()),
+            5.seconds().step_until(1.seconds(), 1.seconds()),
+        ];
+        for mut range in cases {
+            loop {
+                let expected = range.clone().count();
+                assert_eq!(range.size_hint(), (expected, Some(expected)));
+                if range.next().is_none() {
+                    break;
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "duration range step must be greater than zero")]
+    fn test_zero_step_panics() {
+        let _ = 1.seconds().step_until(2.seconds(), Duration::ZERO);
+    }
+}
@@ -0,0 +1,234 @@
+use std::time::{Duration, Instant};
+
+/// A rate limiter that allows one action per interval, with an optional burst.
+///
+/// Token-bucket semantics: the bucket holds up to [`burst`](Self::burst) tokens
+/// (one by default) and refills one token every interval. Each allowed action takes a
+/// token, so a throttle that has been idle can absorb a short burst before falling
+/// back to the steady rate. Time is read from the monotonic clock.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, Throttle};
+///
+/// let mut throttle = Throttle::new(1.seconds()).burst(2);
+/// assert!(throttle.try_acquire());
+/// assert!(throttle.try_acquire());
+/// assert!(!throttle.try_acquire());
+/// assert!(throttle.time_until_ready() <= 1.seconds());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Throttle {
+    interval: Duration,
+    burst: u32,
+    /// When the bucket will be full again, or `None` if it already is.
+    full_at: Option<Instant>,
+}
+
+impl Throttle {
+    /// Creates a throttle that allows one action every `interval`.
+    pub fn new(interval: Duration) -> Self {
+        Throttle { interval, burst: 1, full_at: None }
+    }
+
+    /// Lets up to `burst` actions through at once after an idle period.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `burst` is zero.
+    #[track_caller]
+    pub fn burst(mut self, burst: u32) -> Self {
+        assert!(burst > 0, "throttle burst must be at least 1");
+        self.burst = burst;
+        self
+    }
+
+    /// Takes a token and returns `true` if an action is allowed now.
+    pub fn try_acquire(&mut self) -> bool {
+        self.try_acquire_at(Instant::now())
+    }
+
+    /// Returns how long until [`try_acquire`](Self::try_acquire) will succeed, zero if it would now.
+    pub fn time_until_ready(&self) -> Duration {
+        self.time_until_ready_at(Instant::now())
+    }
+
+    /// Refills the bucket, as if the throttle had been idle.
+    pub fn reset(&mut self) {
+        self.full_at = None;
+    }
+
+    /// How far in the future `full_at` may lie while a token is still available.
+    fn tolerance(&self) -> Duration {
+        self.interval.saturating_mul(self.burst - 1)
+    }
+
+    fn try_acquire_at(&mut self, now: Instant) -> bool {
+        let full_at = self.full_at.map_or(now, |full_at| full_at.max(now));
+        if full_at.duration_since(now) > self.tolerance() {
+            return false;
+        }
+        // Taking a token pushes the refill time one interval further out.
+        self.full_at = Some(full_at.checked_add(self.interval).unwrap_or(full_at));
+        true
+    }
+
+    fn time_until_ready_at(&self, now: Instant) -> Duration {
+        let full_in = self.full_at.map_or(Duration::ZERO, |full_at| full_at.saturating_duration_since(now));
+        full_in.saturating_sub(self.tolerance())
+    }
+}
+
+/// Collapses a burst of events into one, fired after a quiet period.
+///
+/// Every [`trigger`](Self::trigger) restarts the wait; [`poll`](Self::poll) returns
+/// `true` exactly once when `wait` has passed since the last trigger. Typical uses are
+/// saving after the user stops typing or reloading after a flurry of file events.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{Debounce, DurationExt, DurationSleepExt};
+///
+/// let mut debounce = Debounce::new(20.milliseconds());
+/// debounce.trigger();
+/// debounce.trigger();
+/// assert!(!debounce.poll());
+///
+/// 20.milliseconds().sleep_thread();
+/// assert!(debounce.poll());
+/// assert!(!debounce.poll());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Debounce {
+    wait: Duration,
+    /// When the pending event fires, or `None` if nothing is pending.
+    fires_at: Option<Instant>,
+}
+
+impl Debounce {
+    /// Creates a debounce that fires once `wait` has passed without a new trigger.
+    pub fn new(wait: Duration) -> Self {
+        Debounce { wait, fires_at: None }
+    }
+
+    /// Records an event, restarting the quiet period.
+    pub fn trigger(&mut self) {
+        self.trigger_at(Instant::now());
+    }
+
+    /// Returns `true` once the quiet period after the last trigger has passed, then clears it.
+    pub fn poll(&mut self) -> bool {
+        self.poll_at(Instant::now())
+    }
+
+    /// Returns `true` if an event has been triggered but not yet fired.
+    pub fn is_pending(&self) -> bool {
+        self.fires_at.is_some()
+    }
+
+    /// Returns when the pending event fires, or `None` if nothing is pending.
+    ///
+    /// Sleep until this instant instead of polling in a loop.
+    pub fn deadline(&self) -> Option<Instant> {
+        self.fires_at
+    }
+
+    /// Drops the pending event without firing it.
+    pub fn cancel(&mut self) {
+        self.fires_at = None;
+    }
+
+    fn trigger_at(&mut self, now: Instant) {
+        self.fires_at = Some(now.checked_add(self.wait).unwrap_or(now));
+    }
+
+    fn poll_at(&mut self, now: Instant) -> bool {
+        match self.fires_at {
+            Some(fires_at) if fires_at <= now => {
+                self.fires_at = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_throttle_one_per_interval() {
+        let origin = Instant::now();
+        let mut throttle = Throttle::new(1.seconds());
+        assert!(throttle.try_acquire_at(origin));
+        assert!(!throttle.try_acquire_at(origin + 500.milliseconds()));
+        assert_eq!(throttle.time_until_ready_at(origin + 500.milliseconds()), 500.milliseconds());
+        assert!(throttle.try_acquire_at(origin + 1.seconds()));
+        assert!(!throttle.try_acquire_at(origin + 1.seconds()));
+    }
+
+    #[test]
+    fn test_throttle_burst_refills() {
+        let origin = Instant::now();
+        let mut throttle = Throttle::new(1.seconds()).burst(3);
+        assert!((0..3).all(|_| throttle.try_acquire_at(origin)));
+        assert!(!throttle.try_acquire_at(origin));
+        assert_eq!(throttle.time_until_ready_at(origin), 1.seconds());
+
+        // Two intervals later two tokens are back, not three.
+        let later = origin + 2.seconds();
+        assert!(throttle.try_acquire_at(later));
+        assert!(throttle.try_acquire_at(later));
+        assert!(!throttle.try_acquire_at(later));
+    }
+
+    #[test]
+    fn test_throttle_idle_caps_at_burst() {
+        let origin = Instant::now();
+        let mut throttle = Throttle::new(1.seconds()).burst(2);
+        assert!(throttle.try_acquire_at(origin));
+        let later = origin + 1.hours();
+        assert_eq!(throttle.time_until_ready_at(later), Duration::ZERO);
+        assert_eq!((0..5).filter(|_| throttle.try_acquire_at(later)).count(), 2);
+
+        throttle.reset();
+        assert!(throttle.try_acquire_at(later));
+    }
+
+    #[test]
+    #[should_panic(expected = "throttle burst must be at least 1")]
+    fn test_zero_burst_panics() {
+        let _ = Throttle::new(1.seconds()).burst(0);
+    }
+
+    #[test]
+    fn test_debounce_fires_once_after_quiet_period() {
+        let origin = Instant::now();
+        let mut debounce = Debounce::new(300.milliseconds());
+        assert!(!debounce.poll_at(origin));
+
+        debounce.trigger_at(origin);
+        debounce.trigger_at(origin + 200.milliseconds());
+        assert!(!debounce.poll_at(origin + 400.milliseconds()));
+        assert_eq!(debounce.deadline(), Some(origin + 500.milliseconds()));
+
+        assert!(debounce.poll_at(origin + 500.milliseconds()));
+        assert!(!debounce.poll_at(origin + 600.milliseconds()));
+        assert!(!debounce.is_pending());
+    }
+
+    #[test]
+    fn test_debounce_cancel() {
+        let origin = Instant::now();
+        let mut debounce = Debounce::new(1.seconds());
+        debounce.trigger_at(origin);
+        assert!(debounce.is_pending());
+        debounce.cancel();
+        assert!(!debounce.poll_at(origin + 2.seconds()));
+    }
+}
@@ -0,0 +1,155 @@
+use std::thread;
+use std::time::Duration;
+
+use crate::Backoff;
+
+/// Runs a fallible operation repeatedly, sleeping between attempts according to a [`Backoff`].
+///
+/// The operation is attempted once, then retried once per delay the backoff yields, so
+/// `Backoff::exponential(..).max_retries(3)` allows up to four attempts. After the
+/// backoff is exhausted the last error is returned. A backoff without `max_retries`
+/// retries until the operation succeeds.
+///
+/// Sleeping blocks the current thread; async code should drive the backoff iterator
+/// with its runtime's sleep instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{Backoff, DurationExt, RetryPolicy};
+///
+/// let policy = RetryPolicy::new(Backoff::exponential(1.milliseconds()).max_retries(3));
+///
+/// let mut calls = 0;
+/// let result = policy.run(|| {
+///     calls += 1;
+///     if calls < 3 { Err("unavailable") } else { Ok(calls) }
+/// });
+/// assert_eq!(result, Ok(3));
+///
+/// let result: Result<(), _> = policy.run(|| Err("down"));
+/// assert_eq!(result, Err("down"));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    backoff: Backoff,
+}
+
+impl RetryPolicy {
+    /// Creates a policy that waits according to `backoff` between attempts.
+    ///
+    /// Every run starts from the beginning of the backoff sequence.
+    pub fn new(backoff: Backoff) -> Self {
+        RetryPolicy { backoff }
+    }
+
+    /// Runs `operation` until it succeeds or the backoff is exhausted.
+    pub fn run<T, E, F>(&self, operation: F) -> Result<T, E>
+    where
+        F: FnMut() -> Result<T, E>,
+    {
+        self.run_if(operation, |_| true)
+    }
+
+    /// Like [`run`](Self::run), but only retries errors for which `should_retry` returns
+    /// `true`; any other error is returned immediately.
+    pub fn run_if<T, E, F, P>(&self, operation: F, should_retry: P) -> Result<T, E>
+    where
+        F: FnMut() -> Result<T, E>,
+        P: FnMut(&E) -> bool,
+    {
+        self.run_with_sleep(operation, should_retry, thread::sleep)
+    }
+
+    fn run_with_sleep<T, E, F, P, S>(&self, mut operation: F, mut should_retry: P, mut sleep: S) -> Result<T, E>
+    where
+        F: FnMut() -> Result<T, E>,
+        P: FnMut(&E) -> bool,
+        S: FnMut(Duration),
+    {
+        let mut delays = self.backoff.clone();
+        delays.reset();
+        loop {
+            let error = match operation() {
+                Ok(value) => return Ok(value),
+                Err(error) => error,
+            };
+            if !should_retry(&error) {
+                return Err(error);
+            }
+            match delays.next() {
+                Some(delay) => sleep(delay),
+                None => return Err(error),
+            }
+        }
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    fn policy() -> RetryPolicy {
+        RetryPolicy::new(Backoff::exponential(100.milliseconds()).max_retries(3))
+    }
+
+    #[test]
+    fn test_succeeds_after_retries() {
+        let mut slept = Vec::new();
+        let mut calls = 0;
+        let result = policy().run_with_sleep(
+            || {
+                calls += 1;
+                if calls < 3 { Err(calls) } else { Ok("done") }
+            },
+            |_| true,
+            |delay| slept.push(delay),
+        );
+        assert_eq!(result, Ok("done"));
+        assert_eq!(slept, [100.milliseconds(), 200.milliseconds()]);
+    }
+
+    #[test]
+    fn test_returns_last_error_after_exhaustion() {
+        let mut slept = Vec::new();
+        let mut calls = 0;
+        let result: Result<(), _> = policy().run_with_sleep(
+            || {
+                calls += 1;
+                Err(calls)
+            },
+            |_| true,
+            |delay| slept.push(delay),
+        );
+        assert_eq!(result, Err(4));
+        assert_eq!(slept, [100.milliseconds(), 200.milliseconds(), 400.milliseconds()]);
+    }
+
+    #[test]
+    fn test_run_if_stops_on_permanent_error() {
+        let mut slept = Vec::new();
+        let mut errors = ["transient", "permanent", "transient"].into_iter();
+        let result: Result<(), _> = policy().run_with_sleep(
+            || Err(errors.next().unwrap()),
+            |error| *error == "transient",
+            |delay| slept.push(delay),
+        );
+        assert_eq!(result, Err("permanent"));
+        assert_eq!(slept, [100.milliseconds()]);
+    }
+
+    #[test]
+    fn test_each_run_restarts_backoff() {
+        let policy = RetryPolicy::new(Backoff::constant(1.milliseconds()).max_retries(1));
+        for _ in 0..2 {
+            let mut calls = 0;
+            let result: Result<(), _> = policy.run(|| {
+                calls += 1;
+                Err(calls)
+            });
+            assert_eq!(result, Err(2));
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use core::time::Duration;
+
+use crate::value::{duration_from_nanos, NANOS_PER_HOUR, NANOS_PER_MICRO, NANOS_PER_MILLI, NANOS_PER_MINUTE, NANOS_PER_SEC};
+
+/// A unit of time that durations can be rounded or truncated to.
+///
+/// Days and weeks are fixed spans of 24 and 168 hours, not calendar units.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Unit {
+    /// One nanosecond.
+    Nanos,
+    /// One microsecond.
+    Micros,
+    /// One millisecond.
+    Millis,
+    /// One second.
+    Seconds,
+    /// One minute.
+    Minutes,
+    /// One hour.
+    Hours,
+    /// A fixed span of 24 hours.
+    Days,
+    /// A fixed span of 168 hours.
+    Weeks,
+}
+
+impl Unit {
+    /// Returns the length of this unit in nanoseconds.
+    pub const fn as_nanos(self) -> u64 {
+        match self {
+            Unit::Nanos => 1,
+            Unit::Micros => NANOS_PER_MICRO,
+            Unit::Millis => NANOS_PER_MILLI,
+            Unit::Seconds => NANOS_PER_SEC,
+            Unit::Minutes => NANOS_PER_MINUTE,
+            Unit::Hours => NANOS_PER_HOUR,
+            Unit::Days => 24 * NANOS_PER_HOUR,
+            Unit::Weeks => 7 * 24 * NANOS_PER_HOUR,
+        }
+    }
+
+    /// Returns the length of this unit as a `Duration`.
+    pub const fn as_duration(self) -> Duration {
+        Duration::from_nanos(self.as_nanos())
+    }
+}
+
+/// An extension trait that rounds a `Duration` to a whole multiple of a [`Unit`].
+///
+/// Useful for stable bucketing of measured durations, e.g. in metrics.
+/// [`round_to`](Self::round_to) rounds half up, so 1.5s rounds to 2s.
+///
+/// # Panics
+///
+/// [`round_to`](Self::round_to) and [`ceil_to`](Self::ceil_to) panic if rounding up
+/// exceeds `Duration`'s capacity, which can only happen within one unit of
+/// `Duration::MAX`. Their `checked_*` counterparts return `None` instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, DurationRoundExt, Unit};
+///
+/// let elapsed = 2.seconds() + 500.milliseconds();
+/// assert_eq!(elapsed.round_to(Unit::Seconds), 3.seconds());
+/// assert_eq!(elapsed.trunc_to(Unit::Seconds), 2.seconds());
+/// assert_eq!(elapsed.ceil_to(Unit::Minutes), 1.minutes());
+/// ```
+pub trait DurationRoundExt {
+    /// Rounds this duration to the nearest multiple of `unit`, with ties rounding up.
+    fn round_to(self, unit: Unit) -> Duration;
+    /// Rounds this duration down to a multiple of `unit`.
+    fn trunc_to(self, unit: Unit) -> Duration;
+    /// Rounds this duration up to a multiple of `unit`.
+    fn ceil_to(self, unit: Unit) -> Duration;
+    /// Like [`round_to`](Self::round_to), but returns `None` on overflow.
+    fn checked_round_to(self, unit: Unit) -> Option<Duration>;
+    /// Like [`ceil_to`](Self::ceil_to), but returns `None` on overflow.
+    fn checked_ceil_to(self, unit: Unit) -> Option<Duration>;
+}
+
+impl DurationRoundExt for Duration {
+    #[track_caller]
+    fn round_to(self, unit: Unit) -> Duration {
+        match self.checked_round_to(unit) {
+            Some(rounded) => rounded,
+            None => panic!("rounding {:?} to {:?} overflows u64 seconds capacity", self, unit),
+        }
+    }
+
+    fn trunc_to(self, unit: Unit) -> Duration {
+        let nanos = self.as_nanos();
+        let remainder = nanos % u128::from(unit.as_nanos());
+        // Truncating never increases the duration, so it always fits.
+        duration_from_nanos(nanos - remainder).unwrap_or(self)
+    }
+
+    #[track_caller]
+    fn ceil_to(self, unit: Unit) -> Duration {
+        match self.checked_ceil_to(unit) {
+            Some(rounded) => rounded,
+            None => panic!("rounding {:?} up to {:?} overflows u64 seconds capacity", self, unit),
+        }
+    }
+
+    fn checked_round_to(self, unit: Unit) -> Option<Duration> {
+        let (nanos, unit_nanos) = (self.as_nanos(), u128::from(unit.as_nanos()));
+        let remainder = nanos % unit_nanos;
+        if remainder * 2 >= unit_nanos {
+            duration_from_nanos(nanos - remainder + unit_nanos)
+        } else {
+            duration_from_nanos(nanos - remainder)
+        }
+    }
+
+    fn checked_ceil_to(self, unit: Unit) -> Option<Duration> {
+        let (nanos, unit_nanos) = (self.as_nanos(), u128::from(unit.as_nanos()));
+        match nanos % unit_nanos {
+            0 => Some(self),
+            remainder => duration_from_nanos(nanos - remainder + unit_nanos),
+        }
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_unit_lengths() {
+        assert_eq!(Unit::Millis.as_duration(), 1.milliseconds());
+        assert_eq!(Unit::Days.as_duration(), 24.hours());
+        assert_eq!(Unit::Weeks.as_duration(), (7 * 24).hours());
+        assert!(Unit::Seconds < Unit::Minutes);
+    }
+
+    #[test]
+    fn test_round_to_ties_up() {
+        assert_eq!(1499.milliseconds().round_to(Unit::Seconds), 1.seconds());
+        assert_eq!(1500.milliseconds().round_to(Unit::Seconds), 2.seconds());
+        assert_eq!(29.seconds().round_to(Unit::Minutes), Duration::ZERO);
+        assert_eq!(30.seconds().round_to(Unit::Minutes), 1.minutes());
+        assert_eq!(12.hours().round_to(Unit::Days), 24.hours());
+    }
+
+    #[test]
+    fn test_trunc_and_ceil() {
+        let value = 2.hours() + 1.nanoseconds();
+        assert_eq!(value.trunc_to(Unit::Hours), 2.hours());
+        assert_eq!(value.ceil_to(Unit::Hours), 3.hours());
+        assert_eq!(2.hours().ceil_to(Unit::Hours), 2.hours());
+        assert_eq!(value.trunc_to(Unit::Nanos), value);
+        assert_eq!(Duration::ZERO.ceil_to(Unit::Weeks), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_rounding_near_max() {
+        assert_eq!(Duration::MAX.trunc_to(Unit::Seconds), Duration::from_secs(u64::MAX));
+        assert_eq!(Duration::MAX.checked_ceil_to(Unit::Seconds), None);
+        assert_eq!(Duration::MAX.checked_round_to(Unit::Seconds), None);
+        assert_eq!(Duration::MAX.checked_round_to(Unit::Nanos), Some(Duration::MAX));
+    }
+
+    #[test]
+    #[should_panic(expected = "overflows u64 seconds capacity")]
+    fn test_ceil_to_panics_on_overflow() {
+        let _ = Duration::MAX.ceil_to(Unit::Minutes);
+    }
+}
@@ -0,0 +1,477 @@
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+use core::time::Duration;
+
+#[cfg(feature = "std")]
+use crate::TimePoint;
+use crate::value::{self, IntoDurationValue, NANOS_PER_HOUR, NANOS_PER_MICRO, NANOS_PER_MILLI, NANOS_PER_MINUTE, NANOS_PER_SEC};
+
+/// A span of time that may be negative: a sign plus a `Duration` magnitude.
+///
+/// `std::time::Duration` cannot represent negative spans, which come up naturally when
+/// diffing two timestamps. `SignedDuration` covers that case and converts back to a
+/// `Duration` losslessly whenever it is not negative. Zero is never negative.
+///
+/// Arithmetic panics on overflow like `Duration`'s operators; use the `checked_*`
+/// methods to get `None` instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, SignedDuration, SignedDurationExt};
+/// use std::time::Duration;
+///
+/// let drift = (-30).signed_seconds();
+/// assert!(drift.is_negative());
+/// assert_eq!(drift.unsigned_abs(), 30.seconds());
+///
+/// let corrected = drift + SignedDuration::from(45.seconds());
+/// assert_eq!(Duration::try_from(corrected), Ok(15.seconds()));
+/// assert!(Duration::try_from(drift).is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SignedDuration {
+    negative: bool,
+    magnitude: Duration,
+}
+
+impl SignedDuration {
+    /// A zero-length span.
+    pub const ZERO: SignedDuration = SignedDuration::positive(Duration::ZERO);
+    /// The largest representable span.
+    pub const MAX: SignedDuration = SignedDuration::positive(Duration::MAX);
+    /// The smallest (most negative) representable span.
+    pub const MIN: SignedDuration = SignedDuration::negative(Duration::MAX);
+
+    /// Creates a non-negative span of `magnitude`.
+    pub const fn positive(magnitude: Duration) -> Self {
+        SignedDuration { negative: false, magnitude }
+    }
+
+    /// Creates a span of `magnitude` in the negative direction.
+    ///
+    /// A zero magnitude gives [`SignedDuration::ZERO`].
+    pub const fn negative(magnitude: Duration) -> Self {
+        SignedDuration { negative: !magnitude.is_zero(), magnitude }
+    }
+
+    const fn with_sign(negative: bool, magnitude: Duration) -> Self {
+        if negative {
+            SignedDuration::negative(magnitude)
+        } else {
+            SignedDuration::positive(magnitude)
+        }
+    }
+
+    /// Returns the span from `start` to `end`, negative if `end` is earlier than `start`.
+    ///
+    /// Works with `Instant` and `SystemTime`. Unlike `SystemTime::duration_since`, a wall
+    /// clock that went backwards yields a negative span instead of an error.
+    #[cfg(feature = "std")]
+    pub fn between<T: TimePoint>(start: T, end: T) -> Self {
+        match end.checked_duration_since(start) {
+            Some(elapsed) => SignedDuration::positive(elapsed),
+            None => SignedDuration::negative(start.checked_duration_since(end).unwrap_or_default()),
+        }
+    }
+
+    /// Returns `true` if this span is below zero.
+    pub const fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns `true` if this span is above zero.
+    pub const fn is_positive(&self) -> bool {
+        !self.negative && !self.magnitude.is_zero()
+    }
+
+    /// Returns `true` if this span is zero.
+    pub const fn is_zero(&self) -> bool {
+        self.magnitude.is_zero()
+    }
+
+    /// Returns the length of this span regardless of its sign.
+    pub const fn unsigned_abs(&self) -> Duration {
+        self.magnitude
+    }
+
+    /// Returns `-1`, `0` or `1` depending on the sign of this span.
+    pub const fn signum(&self) -> i32 {
+        if self.negative {
+            -1
+        } else if self.magnitude.is_zero() {
+            0
+        } else {
+            1
+        }
+    }
+
+    /// Returns this span as a `Duration`, or `None` if it is negative.
+    pub const fn to_std(&self) -> Option<Duration> {
+        if self.negative {
+            None
+        } else {
+            Some(self.magnitude)
+        }
+    }
+
+    /// Returns the sum of two spans, or `None` if the magnitude overflows.
+    pub fn checked_add(self, rhs: SignedDuration) -> Option<SignedDuration> {
+        if self.negative == rhs.negative {
+            let magnitude = self.magnitude.checked_add(rhs.magnitude)?;
+            Some(SignedDuration::with_sign(self.negative, magnitude))
+        } else if self.magnitude >= rhs.magnitude {
+            Some(SignedDuration::with_sign(self.negative, self.magnitude - rhs.magnitude))
+        } else {
+            Some(SignedDuration::with_sign(rhs.negative, rhs.magnitude - self.magnitude))
+        }
+    }
+
+    /// Returns the difference of two spans, or `None` if the magnitude overflows.
+    pub fn checked_sub(self, rhs: SignedDuration) -> Option<SignedDuration> {
+        self.checked_add(-rhs)
+    }
+
+    /// Multiplies this span by `rhs`, or returns `None` if the magnitude overflows.
+    pub fn checked_mul(self, rhs: u32) -> Option<SignedDuration> {
+        Some(SignedDuration::with_sign(self.negative, self.magnitude.checked_mul(rhs)?))
+    }
+
+    /// Divides this span by `rhs`, or returns `None` if `rhs` is zero.
+    pub fn checked_div(self, rhs: u32) -> Option<SignedDuration> {
+        Some(SignedDuration::with_sign(self.negative, self.magnitude.checked_div(rhs)?))
+    }
+}
+
+impl From<Duration> for SignedDuration {
+    fn from(duration: Duration) -> Self {
+        SignedDuration::positive(duration)
+    }
+}
+
+impl TryFrom<SignedDuration> for Duration {
+    type Error = NegativeDurationError;
+
+    fn try_from(duration: SignedDuration) -> Result<Self, Self::Error> {
+        duration.to_std().ok_or(NegativeDurationError)
+    }
+}
+
+/// The error returned when converting a negative [`SignedDuration`] into a `Duration`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NegativeDurationError;
+
+impl fmt::Display for NegativeDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("cannot convert a negative duration into std::time::Duration")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NegativeDurationError {}
+
+impl fmt::Debug for SignedDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.negative {
+            f.write_str("-")?;
+        }
+        fmt::Debug::fmt(&self.magnitude, f)
+    }
+}
+
+impl Ord for SignedDuration {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, false) => self.magnitude.cmp(&other.magnitude),
+            (true, true) => other.magnitude.cmp(&self.magnitude),
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+        }
+    }
+}
+
+impl PartialOrd for SignedDuration {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Neg for SignedDuration {
+    type Output = SignedDuration;
+
+    fn neg(self) -> SignedDuration {
+        SignedDuration::with_sign(!self.negative, self.magnitude)
+    }
+}
+
+impl Add for SignedDuration {
+    type Output = SignedDuration;
+
+    #[track_caller]
+    fn add(self, rhs: SignedDuration) -> SignedDuration {
+        self.checked_add(rhs).expect("overflow when adding signed durations")
+    }
+}
+
+impl Sub for SignedDuration {
+    type Output = SignedDuration;
+
+    #[track_caller]
+    fn sub(self, rhs: SignedDuration) -> SignedDuration {
+        self.checked_sub(rhs).expect("overflow when subtracting signed durations")
+    }
+}
+
+impl AddAssign for SignedDuration {
+    #[track_caller]
+    fn add_assign(&mut self, rhs: SignedDuration) {
+        *self = *self + rhs;
+    }
+}
+
+impl SubAssign for SignedDuration {
+    #[track_caller]
+    fn sub_assign(&mut self, rhs: SignedDuration) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul<u32> for SignedDuration {
+    type Output = SignedDuration;
+
+    #[track_caller]
+    fn mul(self, rhs: u32) -> SignedDuration {
+        self.checked_mul(rhs).expect("overflow when multiplying signed duration by scalar")
+    }
+}
+
+impl Div<u32> for SignedDuration {
+    type Output = SignedDuration;
+
+    #[track_caller]
+    fn div(self, rhs: u32) -> SignedDuration {
+        self.checked_div(rhs).expect("divide by zero error when dividing signed duration by scalar")
+    }
+}
+
+/// An extension trait that creates a [`SignedDuration`] from a numeric primitive,
+/// mirroring [`DurationExt`](crate::DurationExt) but accepting negative values.
+///
+/// The methods carry a `signed_` prefix on purpose. `DurationExt` returns
+/// `std::time::Duration` for every receiver, so `(-30).seconds()` still panics: making it
+/// return a `SignedDuration` for signed integers would change the type of every existing
+/// `5.seconds()` call on an `i32` or `i64`. Asking for a signed span is an explicit choice.
+///
+/// # Panics
+///
+/// Panics if the magnitude overflows `u64` seconds or a float is NaN or infinite.
+/// Every method has a `checked_*` counterpart that returns `None` instead.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, SignedDuration, SignedDurationExt};
+///
+/// assert_eq!((-90).signed_minutes(), -SignedDuration::from(90.minutes()));
+/// assert_eq!(2.signed_hours().to_std(), Some(2.hours()));
+/// assert_eq!((-0.5).signed_seconds().unsigned_abs(), 500.milliseconds());
+/// ```
+pub trait SignedDurationExt {
+    /// Creates a `SignedDuration` from the specified number of seconds.
+    fn signed_seconds(self) -> SignedDuration;
+    /// Creates a `SignedDuration` from the specified number of minutes.
+    fn signed_minutes(self) -> SignedDuration;
+    /// Creates a `SignedDuration` from the specified number of hours.
+    fn signed_hours(self) -> SignedDuration;
+    /// Creates a `SignedDuration` from the specified number of milliseconds.
+    fn signed_milliseconds(self) -> SignedDuration;
+    /// Creates a `SignedDuration` from the specified number of microseconds.
+    fn signed_microseconds(self) -> SignedDuration;
+    /// Creates a `SignedDuration` from the specified number of nanoseconds.
+    fn signed_nanoseconds(self) -> SignedDuration;
+    /// Like [`signed_seconds`](Self::signed_seconds), but returns `None` instead of panicking.
+    fn checked_signed_seconds(self) -> Option<SignedDuration>;
+    /// Like [`signed_minutes`](Self::signed_minutes), but returns `None` instead of panicking.
+    fn checked_signed_minutes(self) -> Option<SignedDuration>;
+    /// Like [`signed_hours`](Self::signed_hours), but returns `None` instead of panicking.
+    fn checked_signed_hours(self) -> Option<SignedDuration>;
+    /// Like [`signed_milliseconds`](Self::signed_milliseconds), but returns `None` instead of panicking.
+    fn checked_signed_milliseconds(self) -> Option<SignedDuration>;
+    /// Like [`signed_microseconds`](Self::signed_microseconds), but returns `None` instead of panicking.
+    fn checked_signed_microseconds(self) -> Option<SignedDuration>;
+    /// Like [`signed_nanoseconds`](Self::signed_nanoseconds), but returns `None` instead of panicking.
+    fn checked_signed_nanoseconds(self) -> Option<SignedDuration>;
+}
+
+#[track_caller]
+fn signed<T: IntoDurationValue>(value: T, nanos_per_unit: u64, unit: &str) -> SignedDuration {
+    let (negative, magnitude) = value::to_signed_duration(value, nanos_per_unit, unit);
+    SignedDuration::with_sign(negative, magnitude)
+}
+
+fn checked_signed<T: IntoDurationValue>(value: T, nanos_per_unit: u64) -> Option<SignedDuration> {
+    let (negative, magnitude) = value.checked_signed_units(nanos_per_unit).ok()?;
+    Some(SignedDuration::with_sign(negative, magnitude))
+}
+
+impl<T: IntoDurationValue> SignedDurationExt for T {
+    #[track_caller]
+    fn signed_seconds(self) -> SignedDuration {
+        signed(self, NANOS_PER_SEC, "seconds")
+    }
+
+    #[track_caller]
+    fn signed_minutes(self) -> SignedDuration {
+        signed(self, NANOS_PER_MINUTE, "minutes")
+    }
+
+    #[track_caller]
+    fn signed_hours(self) -> SignedDuration {
+        signed(self, NANOS_PER_HOUR, "hours")
+    }
+
+    #[track_caller]
+    fn signed_milliseconds(self) -> SignedDuration {
+        signed(self, NANOS_PER_MILLI, "milliseconds")
+    }
+
+    #[track_caller]
+    fn signed_microseconds(self) -> SignedDuration {
+        signed(self, NANOS_PER_MICRO, "microseconds")
+    }
+
+    #[track_caller]
+    fn signed_nanoseconds(self) -> SignedDuration {
+        signed(self, 1, "nanoseconds")
+    }
+
+    fn checked_signed_seconds(self) -> Option<SignedDuration> {
+        checked_signed(self, NANOS_PER_SEC)
+    }
+
+    fn checked_signed_minutes(self) -> Option<SignedDuration> {
+        checked_signed(self, NANOS_PER_MINUTE)
+    }
+
+    fn checked_signed_hours(self) -> Option<SignedDuration> {
+        checked_signed(self, NANOS_PER_HOUR)
+    }
+
+    fn checked_signed_milliseconds(self) -> Option<SignedDuration> {
+        checked_signed(self, NANOS_PER_MILLI)
+    }
+
+    fn checked_signed_microseconds(self) -> Option<SignedDuration> {
+        checked_signed(self, NANOS_PER_MICRO)
+    }
+
+    fn checked_signed_nanoseconds(self) -> Option<SignedDuration> {
+        checked_signed(self, 1)
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_signed_constructors() {
+        assert_eq!((-30).signed_seconds(), SignedDuration::negative(30.seconds()));
+        assert_eq!(30u8.signed_seconds(), SignedDuration::positive(30.seconds()));
+        assert_eq!((-2).signed_hours().unsigned_abs(), 2.hours());
+        assert_eq!((-1.5).signed_minutes(), SignedDuration::negative(90.seconds()));
+        assert_eq!((-5).signed_nanoseconds().unsigned_abs(), 5.nanoseconds());
+    }
+
+    #[test]
+    fn test_zero_is_never_negative() {
+        assert_eq!(SignedDuration::negative(Duration::ZERO), SignedDuration::ZERO);
+        assert_eq!(-SignedDuration::ZERO, SignedDuration::ZERO);
+        assert_eq!((-0.0).signed_seconds().signum(), 0);
+        assert!(!(1.signed_seconds() - 1.signed_seconds()).is_negative());
+    }
+
+    #[test]
+    fn test_checked_constructors() {
+        assert_eq!(i64::MIN.checked_signed_hours(), None);
+        assert_eq!(f64::NAN.checked_signed_seconds(), None);
+        assert_eq!((-7).checked_signed_milliseconds(), Some(SignedDuration::negative(7.milliseconds())));
+    }
+
+    #[test]
+    #[should_panic(expected = "duration value NaN seconds is not finite")]
+    fn test_signed_nan_panics() {
+        let _ = f64::NAN.signed_seconds();
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = (-30).signed_seconds();
+        let b = 45.signed_seconds();
+        assert_eq!(a + b, 15.signed_seconds());
+        assert_eq!(a - b, (-75).signed_seconds());
+        assert_eq!(b - b, SignedDuration::ZERO);
+        assert_eq!(a * 2, (-60).signed_seconds());
+        assert_eq!(a / 4, (-7.5).signed_seconds());
+
+        let mut total = SignedDuration::ZERO;
+        total -= 2.signed_minutes();
+        total += 30.signed_seconds();
+        assert_eq!(total, (-90).signed_seconds());
+    }
+
+    #[test]
+    fn test_checked_arithmetic_overflow() {
+        assert_eq!(SignedDuration::MAX.checked_add(1.signed_nanoseconds()), None);
+        assert_eq!(SignedDuration::MIN.checked_sub(1.signed_nanoseconds()), None);
+        assert_eq!(SignedDuration::MIN.checked_add(SignedDuration::MAX), Some(SignedDuration::ZERO));
+        assert_eq!(SignedDuration::MAX.checked_mul(2), None);
+        assert_eq!(1.signed_seconds().checked_div(0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "overflow when adding signed durations")]
+    fn test_add_panics_on_overflow() {
+        let _ = SignedDuration::MIN + (-1).signed_nanoseconds();
+    }
+
+    #[test]
+    fn test_ordering() {
+        let mut spans = [2.signed_seconds(), (-1).signed_seconds(), SignedDuration::ZERO, (-3).signed_seconds()];
+        spans.sort();
+        assert_eq!(spans, [(-3).signed_seconds(), (-1).signed_seconds(), SignedDuration::ZERO, 2.signed_seconds()]);
+        assert!(SignedDuration::MIN < SignedDuration::MAX);
+    }
+
+    #[test]
+    fn test_std_conversion() {
+        assert_eq!(Duration::try_from(5.signed_seconds()), Ok(5.seconds()));
+        assert_eq!(Duration::try_from((-5).signed_seconds()), Err(NegativeDurationError));
+        assert_eq!(SignedDuration::from(Duration::MAX).to_std(), Some(Duration::MAX));
+    }
+
+    #[test]
+    fn test_debug_shows_sign() {
+        assert_eq!(format!("{:?}", (-1.5).signed_seconds()), "-1.5s");
+        assert_eq!(format!("{:?}", 2.signed_milliseconds()), "2ms");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_between_time_points() {
+        use crate::DeadlineExt;
+        use std::time::{Instant, SystemTime};
+
+        let start = Instant::now();
+        let end = 3.seconds().after(start);
+        assert_eq!(SignedDuration::between(start, end), 3.signed_seconds());
+        assert_eq!(SignedDuration::between(end, start), (-3).signed_seconds());
+
+        let now = SystemTime::now();
+        let earlier = 10.minutes().before(now);
+        assert_eq!(SignedDuration::between(now, earlier), (-10).signed_minutes());
+    }
+}
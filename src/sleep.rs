@@ -0,0 +1,80 @@
+use std::hint;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// An extension trait that blocks the current thread for a `Duration`.
+///
+/// This completes the fluent story for scripts and tests:
+/// `5.seconds().sleep_thread()` instead of `std::thread::sleep(Duration::from_secs(5))`.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, DurationSleepExt};
+/// use std::time::Instant;
+///
+/// let start = Instant::now();
+/// 10.milliseconds().sleep_thread();
+/// assert!(start.elapsed() >= 10.milliseconds());
+/// ```
+pub trait DurationSleepExt {
+    /// Puts the current thread to sleep for at least this duration, see `std::thread::sleep`.
+    fn sleep_thread(self);
+
+    /// Parks the current thread for at most this duration, see `std::thread::park_timeout`.
+    ///
+    /// The thread may wake up early, either spuriously or because another thread
+    /// called `unpark` on it.
+    fn park_timeout_current(self);
+
+    /// Busy-waits for at least this duration without yielding to the scheduler.
+    ///
+    /// Only useful for very short waits where the latency of `sleep_thread` is too
+    /// high; it keeps a CPU core busy for the whole duration.
+    fn spin_wait(self);
+}
+
+impl DurationSleepExt for Duration {
+    fn sleep_thread(self) {
+        thread::sleep(self);
+    }
+
+    fn park_timeout_current(self) {
+        thread::park_timeout(self);
+    }
+
+    fn spin_wait(self) {
+        let start = Instant::now();
+        while start.elapsed() < self {
+            hint::spin_loop();
+        }
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_sleep_thread() {
+        let start = Instant::now();
+        5.milliseconds().sleep_thread();
+        assert!(start.elapsed() >= 5.milliseconds());
+    }
+
+    #[test]
+    fn test_park_timeout_current_returns() {
+        let start = Instant::now();
+        5.milliseconds().park_timeout_current();
+        assert!(start.elapsed() < 5.seconds());
+    }
+
+    #[test]
+    fn test_spin_wait() {
+        let start = Instant::now();
+        200.microseconds().spin_wait();
+        assert!(start.elapsed() >= 200.microseconds());
+    }
+}
@@ -0,0 +1,176 @@
+use std::time::{Duration, Instant};
+
+/// A pausable timer that measures elapsed time and laps on the monotonic clock.
+///
+/// Replaces ad-hoc `Instant::now()` bookkeeping in benchmarks and progress output. All
+/// readings are plain `Duration`s, so they work with the formatting extensions of
+/// this crate. Time spent paused is not counted.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, DurationFormatExt, DurationSleepExt, Stopwatch};
+///
+/// let mut stopwatch = Stopwatch::start();
+/// 5.milliseconds().sleep_thread();
+/// let first = stopwatch.lap();
+/// assert!(first >= 5.milliseconds());
+///
+/// stopwatch.pause();
+/// let frozen = stopwatch.elapsed();
+/// 5.milliseconds().sleep_thread();
+/// assert_eq!(stopwatch.elapsed(), frozen);
+///
+/// println!("took {}", stopwatch.elapsed().to_human_string());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct Stopwatch {
+    /// Time accumulated by running periods that have ended.
+    accumulated: Duration,
+    /// When the current running period began, or `None` while paused.
+    running_since: Option<Instant>,
+    /// The elapsed time at the most recent lap.
+    last_lap: Duration,
+}
+
+impl Stopwatch {
+    /// Creates a paused stopwatch at zero. Call [`resume`](Self::resume) to start it.
+    pub fn new() -> Self {
+        Stopwatch::default()
+    }
+
+    /// Creates a stopwatch that is already running.
+    pub fn start() -> Self {
+        Stopwatch { running_since: Some(Instant::now()), ..Stopwatch::default() }
+    }
+
+    /// Returns the total running time, excluding paused periods.
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed_at(Instant::now())
+    }
+
+    /// Returns the running time since the previous lap (or the start) and begins a new lap.
+    pub fn lap(&mut self) -> Duration {
+        self.lap_at(Instant::now())
+    }
+
+    /// Stops counting time. Does nothing if the stopwatch is already paused.
+    pub fn pause(&mut self) {
+        self.pause_at(Instant::now());
+    }
+
+    /// Continues counting time. Does nothing if the stopwatch is already running.
+    pub fn resume(&mut self) {
+        self.resume_at(Instant::now());
+    }
+
+    /// Returns `true` unless the stopwatch is paused.
+    pub fn is_running(&self) -> bool {
+        self.running_since.is_some()
+    }
+
+    /// Sets the elapsed time and laps back to zero, keeping the stopwatch running or paused.
+    pub fn reset(&mut self) {
+        self.accumulated = Duration::ZERO;
+        self.last_lap = Duration::ZERO;
+        if self.running_since.is_some() {
+            self.running_since = Some(Instant::now());
+        }
+    }
+
+    fn elapsed_at(&self, now: Instant) -> Duration {
+        let running = self.running_since.map_or(Duration::ZERO, |since| now.saturating_duration_since(since));
+        self.accumulated.saturating_add(running)
+    }
+
+    fn lap_at(&mut self, now: Instant) -> Duration {
+        let elapsed = self.elapsed_at(now);
+        let lap = elapsed.saturating_sub(self.last_lap);
+        self.last_lap = elapsed;
+        lap
+    }
+
+    fn pause_at(&mut self, now: Instant) {
+        if self.running_since.is_some() {
+            self.accumulated = self.elapsed_at(now);
+            self.running_since = None;
+        }
+    }
+
+    fn resume_at(&mut self, now: Instant) {
+        if self.running_since.is_none() {
+            self.running_since = Some(now);
+        }
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    /// Returns a stopwatch that started running at `origin`.
+    fn started_at(origin: Instant) -> Stopwatch {
+        let mut stopwatch = Stopwatch::new();
+        stopwatch.resume_at(origin);
+        stopwatch
+    }
+
+    #[test]
+    fn test_elapsed_while_running() {
+        let origin = Instant::now();
+        let stopwatch = started_at(origin);
+        assert_eq!(stopwatch.elapsed_at(origin + 3.seconds()), 3.seconds());
+        assert!(stopwatch.is_running());
+    }
+
+    #[test]
+    fn test_new_is_paused_at_zero() {
+        let stopwatch = Stopwatch::new();
+        assert!(!stopwatch.is_running());
+        assert_eq!(stopwatch.elapsed(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_pause_excludes_paused_time() {
+        let origin = Instant::now();
+        let mut stopwatch = started_at(origin);
+        stopwatch.pause_at(origin + 2.seconds());
+        assert_eq!(stopwatch.elapsed_at(origin + 10.seconds()), 2.seconds());
+
+        stopwatch.resume_at(origin + 10.seconds());
+        assert_eq!(stopwatch.elapsed_at(origin + 11.seconds()), 3.seconds());
+    }
+
+    #[test]
+    fn test_repeated_pause_and_resume_are_no_ops() {
+        let origin = Instant::now();
+        let mut stopwatch = started_at(origin);
+        stopwatch.resume_at(origin + 5.seconds());
+        stopwatch.pause_at(origin + 6.seconds());
+        stopwatch.pause_at(origin + 9.seconds());
+        assert_eq!(stopwatch.elapsed_at(origin + 20.seconds()), 6.seconds());
+    }
+
+    #[test]
+    fn test_laps() {
+        let origin = Instant::now();
+        let mut stopwatch = started_at(origin);
+        assert_eq!(stopwatch.lap_at(origin + 1.seconds()), 1.seconds());
+        stopwatch.pause_at(origin + 3.seconds());
+        stopwatch.resume_at(origin + 7.seconds());
+        assert_eq!(stopwatch.lap_at(origin + 8.seconds()), 3.seconds());
+        assert_eq!(stopwatch.elapsed_at(origin + 8.seconds()), 4.seconds());
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut stopwatch = Stopwatch::start();
+        stopwatch.lap();
+        stopwatch.pause();
+        stopwatch.reset();
+        assert_eq!(stopwatch.elapsed(), Duration::ZERO);
+        assert!(!stopwatch.is_running());
+    }
+}
@@ -0,0 +1,310 @@
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+/// How a [`Ticker`] schedules the next tick when work takes time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TickPolicy {
+    /// Ticks stay on a fixed grid of `start + n * period`, so time spent working does
+    /// not accumulate as drift. Ticks that were missed entirely are skipped, not
+    /// replayed in a burst.
+    #[default]
+    FixedRate,
+    /// Each tick comes one full period after the previous call to
+    /// [`tick`](Ticker::tick), i.e. after the work between ticks has finished.
+    FixedDelay,
+}
+
+/// A blocking interval timer with drift correction and a shutdown handle.
+///
+/// The first tick completes immediately; later ticks follow the [`TickPolicy`]. A
+/// [`TickerHandle`] from [`handle`](Self::handle) stops the ticker from any thread,
+/// waking it up even in the middle of a long wait.
+///
+/// A tick that would fall beyond the range of `Instant`, e.g. with a period of
+/// `Duration::MAX`, never comes: [`tick`](Self::tick) then blocks until the ticker is
+/// stopped instead of overflowing.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, Ticker};
+/// use std::thread;
+///
+/// let mut ticker = Ticker::new(10.milliseconds());
+/// let handle = ticker.handle();
+///
+/// let worker = thread::spawn(move || {
+///     let mut polls = 0;
+///     ticker.run(|| polls += 1);
+///     polls
+/// });
+///
+/// thread::sleep(35.milliseconds());
+/// handle.stop();
+/// assert!(worker.join().unwrap() >= 1);
+/// ```
+#[derive(Debug)]
+pub struct Ticker {
+    period: Duration,
+    policy: TickPolicy,
+    next: Next,
+    handle: TickerHandle,
+}
+
+/// When a [`Ticker`] is next due.
+#[derive(Debug, Clone, Copy)]
+enum Next {
+    /// Before the first tick, which is due immediately.
+    First,
+    At(Instant),
+    /// The next tick lies beyond what `Instant` can represent.
+    Never,
+}
+
+impl Ticker {
+    /// Creates a fixed-rate ticker that ticks every `period`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    #[track_caller]
+    pub fn new(period: Duration) -> Self {
+        assert!(!period.is_zero(), "ticker period must be greater than zero");
+        Ticker { period, policy: TickPolicy::default(), next: Next::First, handle: TickerHandle::default() }
+    }
+
+    /// Sets how the next tick is scheduled, see [`TickPolicy`].
+    pub fn policy(mut self, policy: TickPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns a handle that stops this ticker.
+    pub fn handle(&self) -> TickerHandle {
+        self.handle.clone()
+    }
+
+    /// Blocks until the next tick and returns when it was due, or `None` once stopped.
+    pub fn tick(&mut self) -> Option<Instant> {
+        let now = Instant::now();
+        let due = match (self.next, self.policy) {
+            (Next::First, _) => Some(now),
+            (_, TickPolicy::FixedDelay) => now.checked_add(self.period),
+            (Next::At(next), TickPolicy::FixedRate) => Some(next),
+            (Next::Never, TickPolicy::FixedRate) => None,
+        };
+        if self.handle.wait_until(due) {
+            return None;
+        }
+        let due = due.expect("waiting without a deadline only ends when stopped");
+        self.next = self.next_after(due, Instant::now()).map_or(Next::Never, Next::At);
+        Some(due)
+    }
+
+    /// Calls `f` on every tick until the ticker is stopped.
+    pub fn run<F: FnMut()>(&mut self, mut f: F) {
+        while self.tick().is_some() {
+            f();
+        }
+    }
+
+    /// Returns the first fixed-rate tick after `due` that is not already in the past at `now`,
+    /// or `None` if it is beyond the range of `Instant`.
+    fn next_after(&self, due: Instant, now: Instant) -> Option<Instant> {
+        let behind = now.saturating_duration_since(due);
+        let missed = behind.as_nanos() / self.period.as_nanos();
+        let skip = u32::try_from(missed + 1).unwrap_or(u32::MAX);
+        due.checked_add(self.period.saturating_mul(skip))
+    }
+}
+
+/// Stops a [`Ticker`], possibly from another thread.
+///
+/// Handles are cheap to clone; all clones stop the same ticker.
+#[derive(Debug, Clone, Default)]
+pub struct TickerHandle {
+    stopped: Arc<(Mutex<bool>, Condvar)>,
+}
+
+impl TickerHandle {
+    /// Stops the ticker, waking it if it is waiting for a tick.
+    pub fn stop(&self) {
+        let (stopped, wakeup) = &*self.stopped;
+        *stopped.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = true;
+        wakeup.notify_all();
+    }
+
+    /// Returns `true` once [`stop`](Self::stop) has been called.
+    pub fn is_stopped(&self) -> bool {
+        *self.stopped.0.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Waits until `deadline` and returns `true` if the ticker was stopped before then.
+    ///
+    /// Without a deadline this waits until stopped and always returns `true`.
+    fn wait_until(&self, deadline: Option<Instant>) -> bool {
+        let (stopped, wakeup) = &*self.stopped;
+        let mut guard = stopped.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        loop {
+            if *guard {
+                return true;
+            }
+            guard = match deadline {
+                Some(deadline) => {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        return false;
+                    }
+                    wakeup
+                        .wait_timeout(guard, deadline - now)
+                        .unwrap_or_else(|poisoned| poisoned.into_inner())
+                        .0
+                }
+                None => wakeup.wait(guard).unwrap_or_else(|poisoned| poisoned.into_inner()),
+            };
+        }
+    }
+}
+
+/// An extension trait that runs a closure on a fixed-rate interval: `10.seconds().every(|_| poll())`.
+///
+/// Blocks the current thread. The closure receives the [`TickerHandle`] so it can stop
+/// the loop itself; use [`Ticker`] directly to stop it from elsewhere or to pick a
+/// different [`TickPolicy`].
+///
+/// # Panics
+///
+/// Panics if the duration is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, TickerExt};
+///
+/// let mut remaining = 3;
+/// 1.milliseconds().every(|handle| {
+///     remaining -= 1;
+///     if remaining == 0 {
+///         handle.stop();
+///     }
+/// });
+/// assert_eq!(remaining, 0);
+/// ```
+pub trait TickerExt {
+    /// Calls `f` once per period, starting immediately, until the handle is stopped.
+    fn every<F: FnMut(&TickerHandle)>(self, f: F);
+}
+
+impl TickerExt for Duration {
+    #[track_caller]
+    fn every<F: FnMut(&TickerHandle)>(self, mut f: F) {
+        let mut ticker = Ticker::new(self);
+        let handle = ticker.handle();
+        ticker.run(|| f(&handle));
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DurationExt, DurationSleepExt};
+    use std::thread;
+
+    #[test]
+    fn test_next_after_stays_on_grid() {
+        let ticker = Ticker::new(10.milliseconds());
+        let due = Instant::now();
+        assert_eq!(ticker.next_after(due, due), Some(due + 10.milliseconds()));
+        assert_eq!(ticker.next_after(due, due + 3.milliseconds()), Some(due + 10.milliseconds()));
+        // Missed ticks are skipped, the grid is kept.
+        assert_eq!(ticker.next_after(due, due + 25.milliseconds()), Some(due + 30.milliseconds()));
+        assert_eq!(ticker.next_after(due, due + 30.milliseconds()), Some(due + 40.milliseconds()));
+    }
+
+    #[test]
+    fn test_next_after_overflow() {
+        let due = Instant::now();
+        assert_eq!(Ticker::new(Duration::MAX).next_after(due, due), None);
+    }
+
+    #[test]
+    fn test_unrepresentable_tick_waits_until_stopped() {
+        for policy in [TickPolicy::FixedRate, TickPolicy::FixedDelay] {
+            let mut ticker = Ticker::new(Duration::MAX).policy(policy);
+            let handle = ticker.handle();
+            assert!(ticker.tick().is_some());
+
+            let waiter = thread::spawn(move || ticker.tick());
+            10.milliseconds().sleep_thread();
+            handle.stop();
+            assert_eq!(waiter.join().unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn test_every_with_huge_period() {
+        let mut calls = 0;
+        u64::MAX.seconds().every(|handle| {
+            calls += 1;
+            let handle = handle.clone();
+            thread::spawn(move || {
+                10.milliseconds().sleep_thread();
+                handle.stop();
+            });
+        });
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_fixed_rate_does_not_drift() {
+        let mut ticker = Ticker::new(50.milliseconds());
+        let start = ticker.tick().unwrap();
+        for n in 1..=3 {
+            5.milliseconds().sleep_thread();
+            assert_eq!(ticker.tick(), Some(start + 50.milliseconds() * n));
+        }
+    }
+
+    #[test]
+    fn test_fixed_delay_waits_a_full_period() {
+        let mut ticker = Ticker::new(10.milliseconds()).policy(TickPolicy::FixedDelay);
+        ticker.tick();
+        15.milliseconds().sleep_thread();
+        let before = Instant::now();
+        let due = ticker.tick().unwrap();
+        assert!(due >= before + 10.milliseconds());
+        assert!(Instant::now() >= before + 10.milliseconds());
+    }
+
+    #[test]
+    fn test_stop_wakes_a_waiting_ticker() {
+        let mut ticker = Ticker::new(1.hours());
+        let handle = ticker.handle();
+        assert!(ticker.tick().is_some());
+
+        let waiter = thread::spawn(move || ticker.tick());
+        10.milliseconds().sleep_thread();
+        handle.stop();
+        assert_eq!(waiter.join().unwrap(), None);
+        assert!(handle.is_stopped());
+    }
+
+    #[test]
+    fn test_every_stops_from_closure() {
+        let mut calls = 0;
+        1.milliseconds().every(|handle| {
+            calls += 1;
+            if calls == 3 {
+                handle.stop();
+            }
+        });
+        assert_eq!(calls, 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "ticker period must be greater than zero")]
+    fn test_zero_period_panics() {
+        let _ = Ticker::new(Duration::ZERO);
+    }
+}
@@ -0,0 +1,188 @@
+use core::ops::{Add, AddAssign, Mul, Sub, SubAssign};
+use core::time::Duration;
+
+use crate::value::{self, IntoDurationValue, NANOS_PER_HOUR, NANOS_PER_MICRO, NANOS_PER_MILLI, NANOS_PER_MINUTE, NANOS_PER_SEC};
+use crate::DurationExt;
+
+macro_rules! typed_unit {
+    ($($name:ident => $checked:ident, $nanos_per_unit:expr, $unit:literal;)*) => {$(
+        #[doc = concat!("A count of ", $unit, ", kept distinct from other units by the type system.")]
+        ///
+        /// Converts into `Duration` with `From`/`Into` or
+        /// [`to_duration`](Self::to_duration). Values of the same unit can be added,
+        /// subtracted and scaled without converting. See [`TypedDurationExt`] for the
+        /// fluent constructors.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name<T = u64>(pub T);
+
+        impl<T: IntoDurationValue> $name<T> {
+            #[doc = concat!("Converts these ", $unit, " into a `Duration`.")]
+            ///
+            /// # Panics
+            ///
+            /// Panics if the count is negative, not finite, or overflows, like
+            /// [`DurationExt`].
+            #[track_caller]
+            pub fn to_duration(self) -> Duration {
+                value::to_duration(self.0, $nanos_per_unit, $unit)
+            }
+
+            /// Like [`to_duration`](Self::to_duration), but returns `None` instead of panicking.
+            pub fn checked_to_duration(self) -> Option<Duration> {
+                self.0.$checked()
+            }
+        }
+
+        impl<T: IntoDurationValue> From<$name<T>> for Duration {
+            #[track_caller]
+            fn from(value: $name<T>) -> Duration {
+                value.to_duration()
+            }
+        }
+
+        impl<T: Add<Output = T>> Add for $name<T> {
+            type Output = $name<T>;
+
+            fn add(self, rhs: $name<T>) -> $name<T> {
+                $name(self.0 + rhs.0)
+            }
+        }
+
+        impl<T: Sub<Output = T>> Sub for $name<T> {
+            type Output = $name<T>;
+
+            fn sub(self, rhs: $name<T>) -> $name<T> {
+                $name(self.0 - rhs.0)
+            }
+        }
+
+        impl<T: AddAssign> AddAssign for $name<T> {
+            fn add_assign(&mut self, rhs: $name<T>) {
+                self.0 += rhs.0;
+            }
+        }
+
+        impl<T: SubAssign> SubAssign for $name<T> {
+            fn sub_assign(&mut self, rhs: $name<T>) {
+                self.0 -= rhs.0;
+            }
+        }
+
+        impl<T: Mul<Output = T>> Mul<T> for $name<T> {
+            type Output = $name<T>;
+
+            fn mul(self, rhs: T) -> $name<T> {
+                $name(self.0 * rhs)
+            }
+        }
+    )*};
+}
+
+typed_unit! {
+    Hours => checked_hours, NANOS_PER_HOUR, "hours";
+    Minutes => checked_minutes, NANOS_PER_MINUTE, "minutes";
+    Seconds => checked_seconds, NANOS_PER_SEC, "seconds";
+    Millis => checked_milliseconds, NANOS_PER_MILLI, "milliseconds";
+    Micros => checked_microseconds, NANOS_PER_MICRO, "microseconds";
+    Nanos => checked_nanoseconds, 1, "nanoseconds";
+}
+
+/// An extension trait that wraps a number in a typed unit, e.g. `5.minutes_typed()`.
+///
+/// Functions that take [`Minutes`] instead of a bare number or `Duration` cannot be
+/// called with seconds by mistake; the mix-up becomes a type error. The count is only
+/// validated when it is converted into a `Duration`.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{DurationExt, Minutes, TypedDurationExt};
+/// use std::time::Duration;
+///
+/// fn set_session_ttl(ttl: Minutes<u32>) -> Duration {
+///     ttl.into()
+/// }
+///
+/// assert_eq!(set_session_ttl(30.minutes_typed()), 30.minutes());
+/// assert_eq!(set_session_ttl(20.minutes_typed() + 10.minutes_typed()), 30.minutes());
+/// // set_session_ttl(30.seconds_typed()); // error: expected `Minutes<u32>`, found `Seconds<_>`
+/// ```
+pub trait TypedDurationExt: Sized {
+    /// Wraps this value as [`Hours`].
+    fn hours_typed(self) -> Hours<Self>;
+    /// Wraps this value as [`Minutes`].
+    fn minutes_typed(self) -> Minutes<Self>;
+    /// Wraps this value as [`Seconds`].
+    fn seconds_typed(self) -> Seconds<Self>;
+    /// Wraps this value as [`Millis`].
+    fn milliseconds_typed(self) -> Millis<Self>;
+    /// Wraps this value as [`Micros`].
+    fn microseconds_typed(self) -> Micros<Self>;
+    /// Wraps this value as [`Nanos`].
+    fn nanoseconds_typed(self) -> Nanos<Self>;
+}
+
+impl<T: IntoDurationValue> TypedDurationExt for T {
+    fn hours_typed(self) -> Hours<T> {
+        Hours(self)
+    }
+
+    fn minutes_typed(self) -> Minutes<T> {
+        Minutes(self)
+    }
+
+    fn seconds_typed(self) -> Seconds<T> {
+        Seconds(self)
+    }
+
+    fn milliseconds_typed(self) -> Millis<T> {
+        Millis(self)
+    }
+
+    fn microseconds_typed(self) -> Micros<T> {
+        Micros(self)
+    }
+
+    fn nanoseconds_typed(self) -> Nanos<T> {
+        Nanos(self)
+    }
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions_match_trait_methods() {
+        assert_eq!(Duration::from(2.hours_typed()), 2.hours());
+        assert_eq!(Duration::from(90u32.minutes_typed()), 90.minutes());
+        assert_eq!(Duration::from(1.5.seconds_typed()), 1500.milliseconds());
+        assert_eq!(Duration::from(250.milliseconds_typed()), 250.milliseconds());
+        assert_eq!(Duration::from(7.microseconds_typed()), 7.microseconds());
+        assert_eq!(Duration::from(Nanos(u128::from(u64::MAX))), u64::MAX.nanoseconds());
+    }
+
+    #[test]
+    fn test_same_unit_arithmetic() {
+        let mut total = Minutes(10u32) + Minutes(5) - Minutes(3);
+        assert_eq!(total, Minutes(12));
+        total += Minutes(8);
+        total -= Minutes(5);
+        assert_eq!(total * 2, Minutes(30));
+        assert!(Seconds(59) < Seconds(60));
+    }
+
+    #[test]
+    fn test_checked_to_duration() {
+        assert_eq!(Seconds(-1).checked_to_duration(), None);
+        assert_eq!(Hours(u64::MAX).checked_to_duration(), None);
+        assert_eq!(Hours(1u8).checked_to_duration(), Some(1.hours()));
+    }
+
+    #[test]
+    #[should_panic(expected = "duration cannot be negative: got -5 minutes")]
+    fn test_negative_panics() {
+        let _ = Duration::from(Minutes(-5));
+    }
+}
@@ -0,0 +1,426 @@
+use core::fmt;
+use core::time::Duration;
+
+pub(crate) const NANOS_PER_MICRO: u64 = 1_000;
+pub(crate) const NANOS_PER_MILLI: u64 = 1_000_000;
+pub(crate) const NANOS_PER_SEC: u64 = 1_000_000_000;
+pub(crate) const NANOS_PER_MINUTE: u64 = 60 * NANOS_PER_SEC;
+pub(crate) const NANOS_PER_HOUR: u64 = 60 * NANOS_PER_MINUTE;
+
+/// A numeric primitive that the extension traits of this crate accept as a receiver.
+///
+/// This trait is sealed: it is implemented for every integer primitive, `f32` and
+/// `f64`, and cannot be implemented outside this crate. It exists so that the
+/// negative, overflow and NaN checks live in exactly one place, and so generic code
+/// can accept "anything `.seconds()` works on":
+///
+/// ```rust
+/// use duration_extender::{DurationExt, IntoDurationValue};
+/// use std::time::Duration;
+///
+/// fn timeout_secs<T: IntoDurationValue>(value: T) -> Duration {
+///     value.seconds()
+/// }
+///
+/// assert_eq!(timeout_secs(5u8), timeout_secs(5.0));
+/// ```
+pub trait IntoDurationValue: sealed::Sealed {}
+
+pub(crate) mod sealed {
+    use core::fmt;
+    use core::time::Duration;
+
+    use super::ValueError;
+
+    pub trait Sealed: Copy + fmt::Display {
+        /// Converts this many units of `nanos_per_unit` nanoseconds into a `Duration`.
+        ///
+        /// `nanos_per_unit` must be a whole number of seconds or divide one second evenly.
+        fn checked_units(self, nanos_per_unit: u64) -> Result<Duration, ValueError>;
+
+        /// Returns the period of this many events per unit of `nanos_per_unit` nanoseconds,
+        /// rounded to the nearest nanosecond.
+        fn checked_period(self, nanos_per_unit: u64) -> Result<Duration, ValueError>;
+
+        /// Like [`checked_units`](Self::checked_units), but accepts negative values and
+        /// returns whether the value was negative alongside the magnitude.
+        fn checked_signed_units(self, nanos_per_unit: u64) -> Result<(bool, Duration), ValueError> {
+            Ok((false, self.checked_units(nanos_per_unit)?))
+        }
+    }
+}
+
+/// The reason a value could not be converted into a `Duration`.
+///
+/// Returned by the call-site policy API, see [`Overflow`](crate::Overflow).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ValueError {
+    /// The value is below zero.
+    Negative,
+    /// The resulting seconds exceed `u64::MAX`.
+    Overflow,
+    /// The value is NaN or infinite.
+    NotFinite,
+    /// The value is a rate of zero, which has no period.
+    Zero,
+}
+
+/// Converts `value` units into a `Duration`, panicking with a descriptive message on failure.
+///
+/// `unit` is the plural unit name used in the panic message, e.g. `"minutes"`.
+#[track_caller]
+pub(crate) fn to_duration<T: IntoDurationValue>(value: T, nanos_per_unit: u64, unit: &str) -> Duration {
+    // A `match`, not `unwrap_or_else`: closures don't inherit `#[track_caller]`.
+    match value.checked_units(nanos_per_unit) {
+        Ok(duration) => duration,
+        Err(error) => conversion_failed(value, unit, error),
+    }
+}
+
+/// Like [`to_duration`], but accepts negative values and returns whether `value` was negative.
+#[track_caller]
+pub(crate) fn to_signed_duration<T: IntoDurationValue>(value: T, nanos_per_unit: u64, unit: &str) -> (bool, Duration) {
+    match value.checked_signed_units(nanos_per_unit) {
+        Ok(signed) => signed,
+        Err(error) => conversion_failed(value, unit, error),
+    }
+}
+
+#[track_caller]
+fn conversion_failed(value: impl fmt::Display, unit: &str, error: ValueError) -> ! {
+    match error {
+        ValueError::Negative => panic!("duration cannot be negative: got {} {}", value, unit),
+        ValueError::Overflow => panic!("duration value {} {} overflows u64 seconds capacity", value, unit),
+        ValueError::NotFinite => panic!("duration value {} {} is not finite", value, unit),
+        ValueError::Zero => unreachable!("unit conversions never report a zero rate"),
+    }
+}
+
+/// Converts a rate of `value` events per unit into its period, panicking with a
+/// descriptive message on failure.
+///
+/// `unit` is the rate unit used in the panic message, e.g. `"per minute"`.
+#[track_caller]
+pub(crate) fn to_period<T: IntoDurationValue>(value: T, nanos_per_unit: u64, unit: &str) -> Duration {
+    match value.checked_period(nanos_per_unit) {
+        Ok(duration) => duration,
+        Err(ValueError::Negative) => panic!("rate cannot be negative: got {} {}", value, unit),
+        Err(ValueError::Overflow) => panic!("period of {} {} overflows u64 seconds capacity", value, unit),
+        Err(ValueError::NotFinite) => panic!("rate {} {} is not finite", value, unit),
+        Err(ValueError::Zero) => panic!("rate cannot be zero: got {} {}", value, unit),
+    }
+}
+
+/// Converts a nanosecond count into a `Duration`, or `None` if the seconds exceed `u64::MAX`.
+#[inline]
+pub(crate) fn duration_from_nanos(nanos: u128) -> Option<Duration> {
+    let secs = u64::try_from(nanos / u128::from(NANOS_PER_SEC)).ok()?;
+    Some(Duration::new(secs, (nanos % u128::from(NANOS_PER_SEC)) as u32))
+}
+
+/// Converts a `u64` count of units, see [`sealed::Sealed::checked_units`].
+#[inline]
+fn units_from_u64(value: u64, nanos_per_unit: u64) -> Result<Duration, ValueError> {
+    if nanos_per_unit >= NANOS_PER_SEC {
+        let secs = value
+            .checked_mul(nanos_per_unit / NANOS_PER_SEC)
+            .ok_or(ValueError::Overflow)?;
+        Ok(Duration::from_secs(secs))
+    } else {
+        let units_per_sec = NANOS_PER_SEC / nanos_per_unit;
+        let nanos = (value % units_per_sec) * nanos_per_unit;
+        Ok(Duration::new(value / units_per_sec, nanos as u32))
+    }
+}
+
+/// Converts a `u128` count of units, see [`sealed::Sealed::checked_units`].
+///
+/// Unlike a `u64` count, a `u128` count of sub-second units can exceed `Duration`'s
+/// capacity, so the whole seconds are range checked for every unit.
+#[inline]
+fn units_from_u128(value: u128, nanos_per_unit: u64) -> Result<Duration, ValueError> {
+    if nanos_per_unit >= NANOS_PER_SEC {
+        let secs = value
+            .checked_mul(u128::from(nanos_per_unit / NANOS_PER_SEC))
+            .and_then(|secs| u64::try_from(secs).ok())
+            .ok_or(ValueError::Overflow)?;
+        Ok(Duration::from_secs(secs))
+    } else {
+        let units_per_sec = u128::from(NANOS_PER_SEC / nanos_per_unit);
+        let secs = u64::try_from(value / units_per_sec).map_err(|_| ValueError::Overflow)?;
+        let nanos = (value % units_per_sec) as u64 * nanos_per_unit;
+        Ok(Duration::new(secs, nanos as u32))
+    }
+}
+
+/// Returns the period of `rate` events per unit, see [`sealed::Sealed::checked_period`].
+///
+/// Ties round up, so `3` per second is 333,333,333ns and `2_000_000_000` per second
+/// (half a nanosecond) is 1ns.
+#[inline]
+fn period_from_u128(rate: u128, nanos_per_unit: u64) -> Result<Duration, ValueError> {
+    if rate == 0 {
+        return Err(ValueError::Zero);
+    }
+    // `rate / 2` is at most 2^127, so adding a nanosecond count of at most one hour cannot overflow.
+    let nanos = (u128::from(nanos_per_unit) + rate / 2) / rate;
+    // The period never exceeds one unit, so it always fits.
+    Ok(Duration::from_nanos(nanos as u64))
+}
+
+/// Returns the period of a fractional `rate`, see [`sealed::Sealed::checked_period`].
+#[inline]
+fn period_from_f64(rate: f64, nanos_per_unit: u64) -> Result<Duration, ValueError> {
+    if rate < 0.0 {
+        return Err(ValueError::Negative);
+    }
+    if !rate.is_finite() {
+        return Err(ValueError::NotFinite);
+    }
+    if rate == 0.0 {
+        return Err(ValueError::Zero);
+    }
+    const MAX_NANOS: f64 = u64::MAX as f64 * NANOS_PER_SEC as f64;
+    let nanos = nanos_per_unit as f64 / rate;
+    if nanos >= MAX_NANOS {
+        return Err(ValueError::Overflow);
+    }
+    // `f64::round` needs `std`; the value is positive, so adding one half and truncating is equivalent.
+    let nanos = (nanos + 0.5) as u128;
+    let secs = u64::try_from(nanos / u128::from(NANOS_PER_SEC)).map_err(|_| ValueError::Overflow)?;
+    Ok(Duration::new(secs, (nanos % u128::from(NANOS_PER_SEC)) as u32))
+}
+
+macro_rules! impl_unsigned {
+    ($($t:ty),*) => {$(
+        impl IntoDurationValue for $t {}
+
+        impl sealed::Sealed for $t {
+            #[inline]
+            fn checked_units(self, nanos_per_unit: u64) -> Result<Duration, ValueError> {
+                units_from_u64(self as u64, nanos_per_unit)
+            }
+
+            #[inline]
+            fn checked_period(self, nanos_per_unit: u64) -> Result<Duration, ValueError> {
+                period_from_u128(self as u128, nanos_per_unit)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_signed {
+    ($($t:ty => $unsigned:ty),*) => {$(
+        impl IntoDurationValue for $t {}
+
+        impl sealed::Sealed for $t {
+            #[inline]
+            fn checked_units(self, nanos_per_unit: u64) -> Result<Duration, ValueError> {
+                if self < 0 {
+                    return Err(ValueError::Negative);
+                }
+                (self as $unsigned).checked_units(nanos_per_unit)
+            }
+
+            #[inline]
+            fn checked_period(self, nanos_per_unit: u64) -> Result<Duration, ValueError> {
+                if self < 0 {
+                    return Err(ValueError::Negative);
+                }
+                (self as $unsigned).checked_period(nanos_per_unit)
+            }
+
+            #[inline]
+            fn checked_signed_units(self, nanos_per_unit: u64) -> Result<(bool, Duration), ValueError> {
+                Ok((self < 0, self.unsigned_abs().checked_units(nanos_per_unit)?))
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_float {
+    ($($t:ty => $from_secs:ident),*) => {$(
+        impl IntoDurationValue for $t {}
+
+        impl sealed::Sealed for $t {
+            #[inline]
+            fn checked_units(self, nanos_per_unit: u64) -> Result<Duration, ValueError> {
+                if self < 0.0 {
+                    return Err(ValueError::Negative);
+                }
+                if !self.is_finite() {
+                    return Err(ValueError::NotFinite);
+                }
+                let secs = if nanos_per_unit >= NANOS_PER_SEC {
+                    self * (nanos_per_unit / NANOS_PER_SEC) as $t
+                } else {
+                    self / (NANOS_PER_SEC / nanos_per_unit) as $t
+                };
+                Duration::$from_secs(secs).map_err(|_| ValueError::Overflow)
+            }
+
+            #[inline]
+            fn checked_period(self, nanos_per_unit: u64) -> Result<Duration, ValueError> {
+                period_from_f64(self as f64, nanos_per_unit)
+            }
+
+            #[inline]
+            fn checked_signed_units(self, nanos_per_unit: u64) -> Result<(bool, Duration), ValueError> {
+                if self < 0.0 {
+                    return Ok((true, (-self).checked_units(nanos_per_unit)?));
+                }
+                Ok((false, self.checked_units(nanos_per_unit)?))
+            }
+        }
+    )*};
+}
+
+impl_unsigned!(u8, u16, u32, u64, usize);
+
+impl IntoDurationValue for u128 {}
+
+impl sealed::Sealed for u128 {
+    #[inline]
+    fn checked_units(self, nanos_per_unit: u64) -> Result<Duration, ValueError> {
+        units_from_u128(self, nanos_per_unit)
+    }
+
+    #[inline]
+    fn checked_period(self, nanos_per_unit: u64) -> Result<Duration, ValueError> {
+        period_from_u128(self, nanos_per_unit)
+    }
+}
+
+impl_signed!(
+    i8 => u8,
+    i16 => u16,
+    i32 => u32,
+    i64 => u64,
+    isize => usize,
+    i128 => u128
+);
+
+impl_float!(f32 => try_from_secs_f32, f64 => try_from_secs_f64);
+
+impl fmt::Display for ValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            ValueError::Negative => "duration cannot be negative",
+            ValueError::Overflow => "duration overflows u64 seconds capacity",
+            ValueError::NotFinite => "duration value is not finite",
+            ValueError::Zero => "rate cannot be zero",
+        };
+        f.write_str(message)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ValueError {}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::sealed::Sealed;
+    use super::*;
+    use crate::DurationExt;
+
+    /// Generates the same conversion tests for every integer receiver type.
+    macro_rules! shared_integer_tests {
+        ($($name:ident: $t:ty),*) => {$(
+            mod $name {
+                use super::*;
+
+                #[test]
+                fn test_every_unit() {
+                    let value: $t = 7;
+                    assert_eq!(value.seconds(), Duration::from_secs(7));
+                    assert_eq!(value.minutes(), Duration::from_secs(7 * 60));
+                    assert_eq!(value.hours(), Duration::from_secs(7 * 3600));
+                    assert_eq!(value.milliseconds(), Duration::from_millis(7));
+                    assert_eq!(value.microseconds(), Duration::from_micros(7));
+                    assert_eq!(value.nanoseconds(), Duration::from_nanos(7));
+                }
+
+                #[test]
+                fn test_zero() {
+                    let zero: $t = 0;
+                    assert_eq!(zero.hours(), Duration::ZERO);
+                    assert_eq!(zero.nanoseconds(), Duration::ZERO);
+                }
+
+                #[test]
+                fn test_max_subsec_units() {
+                    let max = <$t>::MAX;
+                    assert_eq!(max.checked_units(NANOS_PER_MILLI).is_ok(), (max as u128) / 1_000 <= u64::MAX as u128);
+                }
+            }
+        )*};
+    }
+
+    /// Generates negative-value tests for every signed receiver type.
+    macro_rules! shared_signed_tests {
+        ($($name:ident: $t:ty),*) => {$(
+            mod $name {
+                use super::*;
+
+                #[test]
+                fn test_negative_is_rejected() {
+                    let negative: $t = -1;
+                    assert_eq!(negative.checked_units(NANOS_PER_SEC), Err(ValueError::Negative));
+                    assert_eq!(<$t>::MIN.checked_units(NANOS_PER_HOUR), Err(ValueError::Negative));
+                }
+
+                #[test]
+                #[should_panic(expected = "duration cannot be negative: got -1 minutes")]
+                fn test_negative_panics() {
+                    let negative: $t = -1;
+                    let _ = negative.minutes();
+                }
+            }
+        )*};
+    }
+
+    shared_integer_tests!(
+        u8_tests: u8, u16_tests: u16, u32_tests: u32, u64_tests: u64, usize_tests: usize, u128_tests: u128,
+        i8_tests: i8, i16_tests: i16, i32_tests: i32, i64_tests: i64, isize_tests: isize, i128_tests: i128
+    );
+
+    shared_signed_tests!(
+        i8_negative: i8, i16_negative: i16, i32_negative: i32,
+        i64_negative: i64, isize_negative: isize, i128_negative: i128
+    );
+
+    #[test]
+    fn test_overflow_boundary_is_shared() {
+        assert!((u64::MAX / 60).checked_units(NANOS_PER_MINUTE).is_ok());
+        assert_eq!((u64::MAX / 60 + 1).checked_units(NANOS_PER_MINUTE), Err(ValueError::Overflow));
+        assert_eq!((u64::MAX as u128 / 60 + 1).checked_units(NANOS_PER_MINUTE), Err(ValueError::Overflow));
+    }
+
+    #[test]
+    fn test_float_errors() {
+        assert_eq!(f64::NAN.checked_units(NANOS_PER_SEC), Err(ValueError::NotFinite));
+        assert_eq!(f32::INFINITY.checked_units(NANOS_PER_SEC), Err(ValueError::NotFinite));
+        assert_eq!(f64::NEG_INFINITY.checked_units(NANOS_PER_SEC), Err(ValueError::Negative));
+        assert_eq!((-0.5f64).checked_units(NANOS_PER_SEC), Err(ValueError::Negative));
+        assert_eq!(1e300f64.checked_units(NANOS_PER_HOUR), Err(ValueError::Overflow));
+        assert_eq!((-0.0f64).checked_units(NANOS_PER_SEC), Ok(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_signed_units() {
+        assert_eq!((-30i32).checked_signed_units(NANOS_PER_SEC), Ok((true, Duration::from_secs(30))));
+        assert_eq!(i8::MIN.checked_signed_units(NANOS_PER_SEC), Ok((true, Duration::from_secs(128))));
+        assert_eq!(30u8.checked_signed_units(NANOS_PER_SEC), Ok((false, Duration::from_secs(30))));
+        assert_eq!((-1.5f64).checked_signed_units(NANOS_PER_SEC), Ok((true, Duration::from_millis(1500))));
+        assert_eq!(f64::NEG_INFINITY.checked_signed_units(NANOS_PER_SEC), Err(ValueError::NotFinite));
+        assert_eq!(i64::MIN.checked_signed_units(NANOS_PER_HOUR), Err(ValueError::Overflow));
+    }
+
+    #[test]
+    #[should_panic(expected = "duration value NaN seconds is not finite")]
+    fn test_float_nan_panic_message() {
+        let _ = f64::NAN.seconds();
+    }
+}
@@ -0,0 +1,147 @@
+use core::time::Duration;
+
+use crate::value::{self, ValueError};
+use crate::DurationFfiExt;
+
+/// Below 2^53 nanoseconds the count converts to `f64` exactly, so `to_js_millis` only
+/// rounds once.
+const EXACT_NANOS: u128 = 1 << 53;
+
+/// An extension trait that converts a `Duration` into the millisecond numbers used by JavaScript.
+///
+/// JS has no integer type for time: `Date.now()`, `performance.now()` and `setTimeout`
+/// all use `f64` milliseconds. Durations below 2^33 milliseconds (about 99 days) are
+/// lossless: [`from_js_millis`] gives back the exact same `Duration`. Past that point
+/// neighbouring `f64` values are more than a nanosecond apart, and beyond 2^53
+/// nanoseconds (about 104 days) precision degrades further with every doubling.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{from_js_millis, DurationExt, DurationJsExt};
+///
+/// let delay = 1.seconds() + 250.microseconds();
+/// assert_eq!(delay.to_js_millis(), 1000.25);
+/// assert_eq!(from_js_millis(1000.25), Ok(delay));
+///
+/// assert_eq!((30 * 24).hours().to_set_timeout_millis(), i32::MAX);
+/// ```
+pub trait DurationJsExt {
+    /// Returns this duration as fractional milliseconds.
+    fn to_js_millis(self) -> f64;
+
+    /// Returns this duration as a delay for `setTimeout` or `setInterval`.
+    ///
+    /// Browsers store the delay as a 32-bit signed integer and fire immediately when it
+    /// overflows, so longer durations are clamped to `i32::MAX` milliseconds (about 24.8
+    /// days). A partial millisecond is rounded up.
+    fn to_set_timeout_millis(self) -> i32;
+}
+
+impl DurationJsExt for Duration {
+    fn to_js_millis(self) -> f64 {
+        let nanos = self.as_nanos();
+        if nanos <= EXACT_NANOS {
+            // Exact integer nanoseconds and a single correctly rounded division keep
+            // the value recoverable by `from_js_millis`.
+            nanos as f64 / 1e6
+        } else {
+            self.as_secs() as f64 * 1e3 + f64::from(self.subsec_nanos()) / 1e6
+        }
+    }
+
+    fn to_set_timeout_millis(self) -> i32 {
+        self.to_millis_i32().unwrap_or(i32::MAX)
+    }
+}
+
+/// Converts JavaScript milliseconds, e.g. from `performance.now()` differences, into a `Duration`.
+///
+/// The value is rounded to the nearest nanosecond.
+///
+/// # Errors
+///
+/// Returns [`ValueError::Negative`] for negative input, [`ValueError::NotFinite`] for
+/// `NaN` and infinities, and [`ValueError::Overflow`] if the result exceeds `Duration::MAX`.
+/// `-0.0` is accepted as zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use duration_extender::{from_js_millis, DurationExt, ValueError};
+///
+/// assert_eq!(from_js_millis(1500.0), Ok(1500.milliseconds()));
+/// assert_eq!(from_js_millis(-1.0), Err(ValueError::Negative));
+/// assert_eq!(from_js_millis(f64::NAN), Err(ValueError::NotFinite));
+/// ```
+pub fn from_js_millis(millis: f64) -> Result<Duration, ValueError> {
+    if millis < 0.0 {
+        return Err(ValueError::Negative);
+    }
+    if !millis.is_finite() {
+        return Err(ValueError::NotFinite);
+    }
+    if millis >= u64::MAX as f64 {
+        return Err(ValueError::Overflow);
+    }
+    // Splitting off the whole milliseconds keeps the scaling error far below a nanosecond.
+    // `f64::round` needs `std`; the fraction is positive, so adding one half and truncating
+    // is equivalent.
+    let whole = millis as u64;
+    let fraction = millis - whole as f64;
+    let nanos = u128::from(whole) * 1_000_000 + (fraction * 1e6 + 0.5) as u128;
+    value::duration_from_nanos(nanos).ok_or(ValueError::Overflow)
+}
+
+// ===== Tests =====
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DurationExt;
+
+    #[test]
+    fn test_to_js_millis() {
+        assert_eq!(Duration::ZERO.to_js_millis(), 0.0);
+        assert_eq!(1500.milliseconds().to_js_millis(), 1500.0);
+        assert_eq!(1.nanoseconds().to_js_millis(), 0.000001);
+        assert_eq!(Duration::MAX.to_js_millis(), u64::MAX as f64 * 1e3 + 999.999999);
+    }
+
+    #[test]
+    fn test_round_trip_below_two_pow_33_millis() {
+        let limit = (1u64 << 33).milliseconds();
+        for duration in [
+            1.nanoseconds(),
+            123_456_789.nanoseconds(),
+            90.minutes() + 7.nanoseconds(),
+            limit - 1.nanoseconds(),
+            limit - 333_333_333.nanoseconds(),
+        ] {
+            assert_eq!(from_js_millis(duration.to_js_millis()), Ok(duration), "{:?}", duration);
+        }
+        // An odd step spreads the samples over every sub-millisecond remainder.
+        let step = limit / 100_003;
+        for i in 0..100_003 {
+            let duration = step * i;
+            assert_eq!(from_js_millis(duration.to_js_millis()), Ok(duration), "{:?}", duration);
+        }
+    }
+
+    #[test]
+    fn test_from_js_millis_errors() {
+        assert_eq!(from_js_millis(-0.0), Ok(Duration::ZERO));
+        assert_eq!(from_js_millis(-0.5), Err(ValueError::Negative));
+        assert_eq!(from_js_millis(f64::INFINITY), Err(ValueError::NotFinite));
+        assert_eq!(from_js_millis(f64::NEG_INFINITY), Err(ValueError::Negative));
+        assert_eq!(from_js_millis(1e300), Err(ValueError::Overflow));
+        assert_eq!(from_js_millis(u64::MAX as f64 * 1e3 * 2.0), Err(ValueError::Overflow));
+        assert_eq!(from_js_millis(u64::MAX as f64), Err(ValueError::Overflow));
+    }
+
+    #[test]
+    fn test_set_timeout_clamps() {
+        assert_eq!(250.milliseconds().to_set_timeout_millis(), 250);
+        assert_eq!(1.microseconds().to_set_timeout_millis(), 1);
+        assert_eq!(Duration::MAX.to_set_timeout_millis(), i32::MAX);
+    }
+}